@@ -0,0 +1,112 @@
+use std::{process::ExitCode, str::FromStr};
+
+use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand};
+use common::{Runner, Solution};
+
+#[global_allocator]
+static ALLOCATOR: common::profile::Allocator = common::profile::Allocator;
+
+/// Command arguments
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the solution for one or all days
+    Run {
+        /// Day to run, or 'all'
+        day: Selection,
+
+        #[command(flatten)]
+        common: common::Args,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(Self::All);
+        }
+
+        let day = s.parse().map_err(|_| "Expected a day number or 'all'")?;
+
+        if !DAYS.iter().any(|entry| entry.day == day) {
+            return Err("No solution for that day");
+        }
+
+        Ok(Self::Day(day))
+    }
+}
+
+struct Day {
+    day: u8,
+    run: fn(&mut Runner),
+}
+
+const fn day<S: Solution + Default>() -> Day {
+    Day {
+        day: S::DAY,
+        run: Runner::run::<S>,
+    }
+}
+
+const DAYS: [Day; 12] = [
+    day::<day_1::Puzzle>(),
+    day::<day_2::Puzzle>(),
+    day::<day_3::Puzzle>(),
+    day::<day_4::Puzzle>(),
+    day::<day_5::Puzzle>(),
+    day::<day_6::Puzzle>(),
+    day::<day_7::Puzzle>(),
+    day::<day_8::Puzzle>(),
+    day::<day_9::Puzzle>(),
+    day::<day_10::Puzzle>(),
+    day::<day_11::Puzzle>(),
+    day::<day_12::Puzzle>(),
+];
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let Command::Run { day, common } = args.command;
+
+    if let (Selection::All, Some(_)) = (day, common.input_files().next()) {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "Input files can only be given for a single day",
+            )
+            .exit();
+    }
+
+    let mut runner = match Runner::new(common) {
+        Ok(runner) => runner,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let days = DAYS.iter().filter(|entry| match day {
+        Selection::All => true,
+        Selection::Day(day) => entry.day == day,
+    });
+
+    for entry in days {
+        (entry.run)(&mut runner);
+    }
+
+    runner.finish()
+}
@@ -0,0 +1,95 @@
+//! Compares the day 1 part 2 digit [`Scanner`](day_1::Scanner) against searching for every
+//! digit word separately with `find` and `rfind`, which it replaced.
+
+use std::hint::black_box;
+
+use benches::Random;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day_1::Lexicon;
+
+/// Generate calibration lines of random letters, digits and digit words.
+fn synthetic_input(words: &[(&str, u32)], lines: usize) -> String {
+    const FILLER: &[&str] = &[
+        "a", "b", "x", "q", "z", "on", "tw", "thr", "eigh", "nin", "se", "v",
+    ];
+
+    let mut random = Random::default();
+
+    let mut input = String::new();
+
+    for _ in 0..lines {
+        for _ in 0..8 + random.below(24) {
+            match random.below(8) {
+                0 => input += words[random.below(words.len())].0,
+                _ => input += FILLER[random.below(FILLER.len())],
+            }
+        }
+
+        input.push('\n');
+    }
+
+    input
+}
+
+/// Search for every digit word separately, from both ends of the line.
+fn find_each_word(words: &[(&str, u32)], line: &str) -> Option<(u8, u8)> {
+    let first = words
+        .iter()
+        .filter_map(|&(pattern, value)| Some((line.find(pattern)?, value)))
+        .min_by_key(|&(position, _)| position)?;
+
+    let last = words
+        .iter()
+        .filter_map(|&(pattern, value)| Some((line.rfind(pattern)? + pattern.len(), value)))
+        .max_by_key(|&(end, _)| end)?;
+
+    Some((first.1 as u8, last.1 as u8))
+}
+
+fn digit_words(c: &mut Criterion) {
+    let mut lexicon = Lexicon::digits();
+    lexicon.extend(&Lexicon::english());
+
+    let words = lexicon.entries().collect::<Vec<_>>();
+    let input = synthetic_input(&words, 100_000);
+    let scanner = lexicon.scanner();
+    let mut buffer = Vec::new();
+
+    for line in input.lines() {
+        assert_eq!(
+            scanner.first_and_last(line, &mut buffer),
+            find_each_word(&words, line),
+            "Both approaches must agree on {:?}",
+            line
+        );
+    }
+
+    let mut group = c.benchmark_group("day_1/digit_words");
+
+    group.throughput(Throughput::Bytes(input.len() as u64));
+
+    group.bench_function("find_each_word", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .lines()
+                .filter_map(|line| find_each_word(&words, line))
+                .map(|(first, last)| (10 * first + last) as u64)
+                .sum::<u64>()
+        })
+    });
+
+    group.bench_function("scanner", |b| {
+        b.iter(|| {
+            black_box(&input)
+                .lines()
+                .filter_map(|line| scanner.first_and_last(line, &mut buffer))
+                .map(|(first, last)| (10 * first + last) as u64)
+                .sum::<u64>()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(day_1, digit_words);
+criterion_main!(day_1);
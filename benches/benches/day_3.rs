@@ -0,0 +1,163 @@
+//! Measures day 3 on large generated schematics, and compares its gear lookup
+//! against scanning every number for every `*`, which it replaced.
+//!
+//! The large schematic is 10,000 cells square by default, and can be resized
+//! with the `DAY_3_SIZE` environment variable.
+
+use std::hint::black_box;
+
+use benches::Random;
+use common::{Part, Solution};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use day_3::Puzzle;
+
+/// Generate a square schematic of random numbers and symbols.
+fn synthetic_schematic(size: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/@%=-&";
+
+    let mut random = Random::default();
+
+    let mut input = String::with_capacity(size * (size + 1));
+
+    for _ in 0..size {
+        let mut column = 0;
+
+        while column < size {
+            match random.below(16) {
+                0 => {
+                    input.push(SYMBOLS[random.below(SYMBOLS.len())] as char);
+                    column += 1;
+                }
+                1..=4 => {
+                    let length = (1 + random.below(3)).min(size - column);
+                    for _ in 0..length {
+                        input.push((b'0' + random.below(10) as u8) as char);
+                    }
+                    column += length;
+
+                    if column < size {
+                        input.push('.');
+                        column += 1;
+                    }
+                }
+                _ => {
+                    input.push('.');
+                    column += 1;
+                }
+            }
+        }
+
+        input.push('\n');
+    }
+
+    input
+}
+
+/// Sum gear ratios by checking every number against every `*`.
+fn linear_scan(input: &str) -> u128 {
+    let lines = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+    let mut numbers = Vec::new();
+
+    for (row, line) in lines.iter().enumerate() {
+        let mut column = 0;
+
+        while column < line.len() {
+            let length = line[column..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+
+            if length > 0 {
+                let value = std::str::from_utf8(&line[column..column + length])
+                    .unwrap()
+                    .parse::<u128>()
+                    .unwrap();
+
+                numbers.push((row, column, column + length - 1, value));
+                column += length;
+            } else {
+                column += 1;
+            }
+        }
+    }
+
+    let mut sum = 0;
+
+    for (row, line) in lines.iter().enumerate() {
+        for (column, &byte) in line.iter().enumerate() {
+            if byte != b'*' {
+                continue;
+            }
+
+            let adjacent = numbers
+                .iter()
+                .filter(|&&(number_row, start, end, _)| {
+                    row.abs_diff(number_row) <= 1
+                        && start.saturating_sub(1) <= column
+                        && column <= end + 1
+                })
+                .map(|&(.., value)| value)
+                .take(3)
+                .collect::<Vec<_>>();
+
+            if adjacent.len() == 2 {
+                sum += adjacent[0] * adjacent[1];
+            }
+        }
+    }
+
+    sum
+}
+
+fn gear_lookup(c: &mut Criterion) {
+    let puzzle = Puzzle::default();
+    let input = synthetic_schematic(200);
+    let schematic = puzzle.parse(&input).expect("Schematic must parse");
+
+    assert_eq!(
+        common::solve_with(&puzzle, &input, Part::Two),
+        Ok(linear_scan(&input)),
+        "Both approaches must agree"
+    );
+
+    let mut group = c.benchmark_group("day_3/gears_200");
+
+    group.bench_function("linear_scan", |b| b.iter(|| linear_scan(black_box(&input))));
+    group.bench_function("index", |b| b.iter(|| puzzle.part_2(black_box(&schematic))));
+
+    group.finish();
+}
+
+fn large_schematic(c: &mut Criterion) {
+    let size = std::env::var("DAY_3_SIZE")
+        .ok()
+        .and_then(|size| size.parse().ok())
+        .unwrap_or(10_000);
+
+    let puzzle = Puzzle::default();
+    let input = synthetic_schematic(size);
+
+    let mut group = c.benchmark_group(format!("day_3/schematic_{}", size));
+
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+
+    group.bench_function("parse", |b| b.iter(|| puzzle.parse(black_box(&input))));
+
+    let schematic = puzzle.parse(&input).expect("Schematic must parse");
+
+    group.bench_function("part_1", |b| {
+        b.iter(|| puzzle.part_1(black_box(&schematic)))
+    });
+    group.bench_function("part_2", |b| {
+        b.iter(|| puzzle.part_2(black_box(&schematic)))
+    });
+    group.bench_function("stream", |b| {
+        b.iter(|| day_3::totals(black_box(input.as_bytes()), &puzzle.gear))
+    });
+
+    group.finish();
+}
+
+criterion_group!(day_3, gear_lookup, large_schematic);
+criterion_main!(day_3);
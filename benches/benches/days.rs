@@ -0,0 +1,19 @@
+use benches::bench;
+use criterion::{criterion_group, criterion_main};
+
+criterion_group!(
+    days,
+    bench::<day_1::Puzzle>,
+    bench::<day_2::Puzzle>,
+    bench::<day_3::Puzzle>,
+    bench::<day_4::Puzzle>,
+    bench::<day_5::Puzzle>,
+    bench::<day_6::Puzzle>,
+    bench::<day_7::Puzzle>,
+    bench::<day_8::Puzzle>,
+    bench::<day_9::Puzzle>,
+    bench::<day_10::Puzzle>,
+    bench::<day_11::Puzzle>,
+    bench::<day_12::Puzzle>,
+);
+criterion_main!(days);
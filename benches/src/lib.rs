@@ -0,0 +1,109 @@
+//! Criterion benchmarks for every day, measuring parsing separately from
+//! solving each part, on both the example and the real puzzle input.
+//!
+//! Save a baseline before making changes, then compare against it afterwards
+//! to spot performance regressions:
+//!
+//! ```sh
+//! cargo bench -p benches -- --save-baseline main
+//! cargo bench -p benches -- --baseline main
+//! ```
+//!
+//! Real inputs are read from the `inputs` directory at the root of the
+//! workspace, and are skipped if missing.
+
+use std::{hint::black_box, path::PathBuf};
+
+use common::{Part, Solution};
+use criterion::Criterion;
+
+/// Parts which take too long to benchmark against real puzzle inputs.
+const SKIPPED: &[(u8, Part)] = &[(12, Part::Two)];
+
+/// Xorshift generator with a fixed seed, so generated inputs are the same on
+/// every run.
+pub struct Random {
+    state: u64,
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self {
+            state: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+}
+
+impl Random {
+    /// Next number below `bound`.
+    pub fn below(&mut self, bound: usize) -> usize {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state as usize % bound
+    }
+}
+
+/// Path of the real puzzle input for the provided day.
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("inputs")
+        .join(format!("day_{}.txt", day))
+}
+
+/// Benchmark the provided solution on its example and real puzzle input.
+pub fn bench<S: Solution + Default>(c: &mut Criterion) {
+    let solution = S::default();
+
+    bench_input(c, &solution, "example", S::EXAMPLE, &Part::ALL);
+
+    let path = input_path(S::DAY);
+
+    match std::fs::read_to_string(&path) {
+        Ok(input) => {
+            let parts = Part::ALL
+                .into_iter()
+                .filter(|&part| !SKIPPED.contains(&(S::DAY, part)))
+                .collect::<Vec<_>>();
+
+            bench_input(c, &solution, "input", &input, &parts);
+        }
+        Err(error) => eprintln!(
+            "Skipping day {} input: {}: {}",
+            S::DAY,
+            path.display(),
+            error
+        ),
+    }
+}
+
+/// Benchmark parsing and then solving the requested parts of the provided input.
+///
+/// Benchmarks are named `day_N/<name>/parse` and `day_N/<name>/part_P`.
+pub fn bench_input<S: Solution>(
+    c: &mut Criterion,
+    solution: &S,
+    name: &str,
+    input: &str,
+    parts: &[Part],
+) {
+    let mut group = c.benchmark_group(format!("day_{}/{}", S::DAY, name));
+
+    group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(input))));
+
+    let parsed = solution
+        .parse(input)
+        .unwrap_or_else(|error| panic!("{}", error.locate(input).on_day(S::DAY)));
+
+    for &part in parts {
+        group.bench_function(format!("part_{}", part), |b| {
+            b.iter(|| match part {
+                Part::One => solution.part_1(black_box(&parsed)),
+                Part::Two => solution.part_2(black_box(&parsed)),
+            })
+        });
+    }
+
+    group.finish();
+}
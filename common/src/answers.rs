@@ -0,0 +1,239 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Error, Part, Report};
+
+/// Day of the advent calendar, stored as `day_N`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Day(u8);
+
+impl FromStr for Day {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_prefix("day_")
+            .ok_or("Missing 'day_' prefix")?
+            .parse()
+            .map(Day)
+            .map_err(|_| "Could not parse day")
+    }
+}
+
+impl Serialize for Day {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("day_{}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Answers recorded for each part of a single input file.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Recorded {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part_1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part_2: Option<String>,
+}
+
+impl Recorded {
+    fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part_1 = Some(answer),
+            Part::Two => self.part_2 = Some(answer),
+        }
+    }
+}
+
+/// Known-good answers, keyed by day and input file.
+///
+/// Stored as TOML, with one table per input file:
+///
+/// ```toml
+/// [day_4."inputs/day_4.txt"]
+/// part_1 = "26426"
+/// part_2 = "6227972"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<Day, BTreeMap<String, Recorded>>,
+}
+
+impl FromStr for Answers {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let toml = toml::to_string(self).map_err(|_| std::fmt::Error)?;
+
+        write!(f, "{}", toml)
+    }
+}
+
+impl Answers {
+    /// Recorded answer to a part of the puzzle for the provided input file.
+    pub fn get(&self, day: u8, path: &str, part: Part) -> Option<&str> {
+        self.days.get(&Day(day))?.get(path)?.get(part)
+    }
+
+    /// Record every successfully computed answer in the report.
+    pub fn record(&mut self, report: &Report) {
+        let recorded = self
+            .days
+            .entry(Day(report.day))
+            .or_default()
+            .entry(report.path.clone())
+            .or_default();
+
+        for outcome in report.outcomes.iter() {
+            if let Ok(answer) = &outcome.answer {
+                recorded.set(outcome.part, answer.clone());
+            }
+        }
+    }
+
+    /// Compare the requested parts of the report against the recorded answers.
+    pub fn verify(&self, report: &Report, parts: &[Part]) -> Vec<(Part, Verdict)> {
+        parts
+            .iter()
+            .map(|&part| {
+                let expected = self.get(report.day, &report.path, part);
+
+                let verdict = match (expected, report.answer(part)) {
+                    (None, _) | (_, None) => Verdict::Unrecorded,
+                    (Some(_), Some(Err(error))) => Verdict::Fail(error.clone()),
+                    (Some(expected), Some(Ok(actual))) if expected == actual => Verdict::Pass,
+                    (Some(expected), Some(Ok(actual))) => Verdict::Mismatch {
+                        expected: expected.to_string(),
+                        actual: actual.clone(),
+                    },
+                };
+
+                (part, verdict)
+            })
+            .collect()
+    }
+}
+
+/// Result of comparing a computed answer against the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Mismatch { expected: String, actual: String },
+    Fail(Error),
+    Unrecorded,
+}
+
+impl Verdict {
+    /// Whether this verdict indicates a regression.
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Verdict::Mismatch { .. } | Verdict::Fail(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Mismatch { expected, actual } => {
+                write!(f, "mismatch: expected {}, found {}", expected, actual)
+            }
+            Verdict::Fail(error) => write!(f, "fail: {}", error),
+            Verdict::Unrecorded => write!(f, "unrecorded"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Outcome;
+
+    const ANSWERS: &str = r#"[day_4."inputs/day_4.txt"]
+part_1 = "13"
+part_2 = "30"
+"#;
+
+    fn report(part_1: Result<&str, &'static str>) -> Report {
+        Report {
+            day: 4,
+            title: "Scratchcards",
+            path: "inputs/day_4.txt".to_string(),
+            parse_time: Default::default(),
+            outcomes: vec![Outcome {
+                part: Part::One,
+                answer: part_1.map(str::to_string).map_err(Error::from),
+                solve_time: None,
+            }],
+            phases: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let answers = ANSWERS
+            .parse::<Answers>()
+            .expect("Must be able to parse answers");
+
+        assert_eq!(answers.get(4, "inputs/day_4.txt", Part::Two), Some("30"));
+        assert_eq!(answers.get(4, "inputs/alice/day_4.txt", Part::Two), None);
+        assert_eq!(answers.to_string(), ANSWERS);
+    }
+
+    #[test]
+    fn verify_parts() {
+        let answers = ANSWERS
+            .parse::<Answers>()
+            .expect("Must be able to parse answers");
+
+        assert_eq!(
+            answers.verify(&report(Ok("13")), &Part::ALL),
+            vec![(Part::One, Verdict::Pass), (Part::Two, Verdict::Unrecorded)]
+        );
+
+        assert_eq!(
+            answers.verify(&report(Ok("14")), &[Part::One]),
+            vec![(
+                Part::One,
+                Verdict::Mismatch {
+                    expected: "13".to_string(),
+                    actual: "14".to_string()
+                }
+            )]
+        );
+
+        assert!(answers.verify(&report(Err("Oops")), &[Part::One])[0]
+            .1
+            .is_regression());
+    }
+
+    #[test]
+    fn record_answers() {
+        let mut answers = Answers::default();
+
+        answers.record(&report(Ok("13")));
+
+        assert_eq!(answers.get(4, "inputs/day_4.txt", Part::One), Some("13"));
+        assert_eq!(answers.get(4, "inputs/day_4.txt", Part::Two), None);
+    }
+}
@@ -0,0 +1,412 @@
+use std::{
+    io::{BufRead, BufReader, Cursor, Read},
+    process::ExitCode,
+};
+
+use crate::{
+    fetch::Fetcher, input::Input, output, profile, Answers, Error, Format, Outcome, Part, Report,
+    Solution, Verdict,
+};
+
+/// Path used to refer to puzzle input read from stdin.
+const STDIN: &str = "<stdin>";
+
+/// Command arguments shared by every binary
+#[derive(clap::Args, Debug, Clone)]
+#[group(skip)]
+pub struct Args {
+    /// Input files from AoC, or '-' for stdin [default: <INPUTS>/day_N.txt]
+    #[arg(short, long)]
+    pub input: Vec<String>,
+
+    /// Input files given without '--input', such as '-' in 'cat input | day_9 -'
+    #[arg(value_name = "INPUT")]
+    pub files: Vec<String>,
+
+    /// Directory containing 'day_N.txt' input files from AoC
+    #[arg(long, default_value_t = String::from("inputs"))]
+    pub inputs: String,
+
+    /// Only run this part
+    #[arg(short, long)]
+    pub part: Option<Part>,
+
+    /// Format to print answers in
+    #[arg(short, long, value_enum, default_value_t = Format::Text, conflicts_with = "verify")]
+    pub output: Format,
+
+    /// Memory-map input files instead of reading them into memory
+    #[arg(long)]
+    pub mmap: bool,
+
+    /// Print the time, peak memory and work spent on each phase to stderr
+    #[arg(long)]
+    pub profile: bool,
+
+    /// Compare answers against those recorded in the answers file
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Record answers into the answers file
+    #[arg(long, conflicts_with = "verify")]
+    pub record: bool,
+
+    /// Answers file used by '--verify' and '--record'
+    #[arg(long, default_value_t = String::from("answers.toml"))]
+    pub answers: String,
+
+    /// Download missing inputs from AoC into '<INPUTS>'
+    #[arg(long, conflicts_with_all = ["input", "files"])]
+    pub fetch: bool,
+
+    /// File containing the AoC session token, used when 'AOC_SESSION' is not set
+    #[arg(long, default_value_t = String::from(".aoc_session"))]
+    pub session_file: String,
+}
+
+impl Args {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    /// Input files given with '--input', followed by those given without it.
+    pub fn input_files(&self) -> impl Iterator<Item = &String> {
+        self.input.iter().chain(&self.files)
+    }
+
+    fn paths(&self, day: u8) -> Vec<String> {
+        if self.input.is_empty() && self.files.is_empty() {
+            vec![format!("{}/day_{}.txt", self.inputs, day)]
+        } else {
+            self.input_files()
+                .map(|path| match path.as_str() {
+                    "-" => STDIN.to_string(),
+                    path => path.to_string(),
+                })
+                .collect()
+        }
+    }
+}
+
+/// Runs solutions as requested on the command line, keeping track of failures.
+pub struct Runner {
+    args: Args,
+    answers: Answers,
+    fetcher: Option<Fetcher>,
+    reports: Vec<Report>,
+    passed: usize,
+    regressions: usize,
+    unrecorded: usize,
+}
+
+impl Runner {
+    /// Create a runner, loading the answers file if it is needed.
+    pub fn new(args: Args) -> Result<Self, String> {
+        let answers = if args.verify || args.record {
+            match std::fs::read_to_string(&args.answers) {
+                Ok(answers) => answers
+                    .parse()
+                    .map_err(|error| format!("{}: {}", args.answers, error))?,
+                Err(_) if args.record => Answers::default(),
+                Err(error) => return Err(format!("{}: {}", args.answers, error)),
+            }
+        } else {
+            Answers::default()
+        };
+
+        if args.profile {
+            profile::enable();
+        }
+
+        let fetcher = args.fetch.then(|| {
+            Fetcher::new(crate::fetch::YEAR, &args.inputs)
+                .with_session(crate::fetch::session(&args.session_file))
+        });
+
+        Ok(Self {
+            args,
+            answers,
+            fetcher,
+            reports: Vec::new(),
+            passed: 0,
+            regressions: 0,
+            unrecorded: 0,
+        })
+    }
+
+    /// Run the default solution over each of its input files.
+    pub fn run<S: Solution + Default>(&mut self) {
+        self.run_with(&S::default());
+    }
+
+    /// Run the provided solution over each of its input files.
+    ///
+    /// Answers for several input files are printed as a table.
+    pub fn run_with<S: Solution>(&mut self, solution: &S) {
+        let reports = self
+            .args
+            .paths(S::DAY)
+            .into_iter()
+            .filter_map(|path| self.run_input(solution, &path))
+            .collect::<Vec<_>>();
+
+        self.print(reports);
+    }
+
+    /// Solve each input file in a single pass over a buffered reader instead
+    /// of reading it into memory, for inputs too large to hold.
+    ///
+    /// `solve` answers both parts, which are reported as by
+    /// [`run_with`](Self::run_with). The pass is profiled as a single phase, and
+    /// its time is reported as parsing time.
+    pub fn run_stream<S: Solution>(
+        &mut self,
+        mut solve: impl FnMut(&mut dyn BufRead) -> Result<(S::Answer, S::Answer), Error>,
+    ) {
+        let reports = self
+            .args
+            .paths(S::DAY)
+            .into_iter()
+            .filter_map(|path| {
+                let (reader, open) = profile::measure("open", || self.open(S::DAY, &path));
+
+                let mut reader = match reader {
+                    Ok(reader) => reader,
+                    Err(error) => {
+                        eprintln!("Day {}: {}: {}", S::DAY, path, error);
+                        self.regressions += 1;
+                        return None;
+                    }
+                };
+
+                let (answers, phase) = profile::measure("stream", || solve(&mut reader));
+                let answers = answers.map_err(|error| error.on_day(S::DAY).in_file(&path));
+
+                let outcomes = self
+                    .args
+                    .parts()
+                    .into_iter()
+                    .map(|part| Outcome {
+                        part,
+                        answer: match (&answers, part) {
+                            (Ok((answer, _)), Part::One) => Ok(answer.to_string()),
+                            (Ok((_, answer)), Part::Two) => Ok(answer.to_string()),
+                            (Err(error), _) => Err(error.clone()),
+                        },
+                        solve_time: None,
+                    })
+                    .collect();
+
+                let report = Report {
+                    day: S::DAY,
+                    title: S::TITLE,
+                    path,
+                    parse_time: phase.time,
+                    outcomes,
+                    phases: vec![phase],
+                };
+
+                self.conclude(report, open)
+            })
+            .collect::<Vec<_>>();
+
+        self.print(reports);
+    }
+
+    /// Print reports now if they are text, or keep them to print together when finished.
+    fn print(&mut self, reports: Vec<Report>) {
+        match (self.args.output, reports.as_slice()) {
+            (Format::Text, [report]) => print!("{}", report),
+            (Format::Text, reports) if !reports.is_empty() => print!("{}", output::table(reports)),
+            _ => self.reports.extend(reports),
+        }
+    }
+
+    /// Read each input file for a day and pass it to `f` instead of solving it.
+    ///
+    /// Inputs which cannot be read, and errors returned by `f`, are reported
+    /// and count as failures.
+    pub fn each_input(&mut self, day: u8, mut f: impl FnMut(&str, &str) -> Result<(), Error>) {
+        for path in self.args.paths(day) {
+            let result = match self.read(day, &path) {
+                Ok(input) => f(&path, &input)
+                    .map_err(|error| error.locate(&input).on_day(day).in_file(&path).to_string()),
+                Err(error) => Err(format!("Day {}: {}: {}", day, path, error)),
+            };
+
+            if let Err(error) = result {
+                eprintln!("{}", error);
+                self.regressions += 1;
+            }
+        }
+    }
+
+    fn open(&mut self, day: u8, path: &str) -> Result<Box<dyn BufRead>, String> {
+        if path == STDIN {
+            return Ok(Box::new(std::io::stdin().lock()));
+        }
+
+        match &mut self.fetcher {
+            Some(fetcher) => fetcher
+                .fetch(day)
+                .map(|input| Box::new(Cursor::new(input)) as Box<dyn BufRead>)
+                .map_err(|error| error.to_string()),
+            None => std::fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|error| error.to_string()),
+        }
+    }
+
+    fn read(&mut self, day: u8, path: &str) -> Result<Input, String> {
+        if path == STDIN {
+            let mut input = String::new();
+
+            return std::io::stdin()
+                .read_to_string(&mut input)
+                .map(|_| Input::Read(input))
+                .map_err(|error| error.to_string());
+        }
+
+        match &mut self.fetcher {
+            Some(fetcher) => fetcher
+                .fetch(day)
+                .map(Input::Read)
+                .map_err(|error| error.to_string()),
+            None if self.args.mmap => Input::map(path).map_err(|error| error.to_string()),
+            None => std::fs::read_to_string(path)
+                .map(Input::Read)
+                .map_err(|error| error.to_string()),
+        }
+    }
+
+    /// Solve a single input file, returning its report unless verifying.
+    fn run_input<S: Solution>(&mut self, solution: &S, path: &str) -> Option<Report> {
+        let parts = self.args.parts();
+
+        let (input, read) = profile::measure("read", || self.read(S::DAY, path));
+
+        let input = match input {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Day {}: {}: {}", S::DAY, path, error);
+                self.regressions += 1;
+                return None;
+            }
+        };
+
+        let report = if self.args.verify {
+            let recorded = parts
+                .iter()
+                .copied()
+                .filter(|&part| self.answers.get(S::DAY, path, part).is_some())
+                .collect::<Vec<_>>();
+
+            Report::new(solution, path, &input, &recorded)
+        } else {
+            Report::new(solution, path, &input, &parts)
+        };
+
+        self.conclude(report, read)
+    }
+
+    /// Profile, verify or record a report, returning it unless verifying.
+    fn conclude(&mut self, report: Report, read: profile::Phase) -> Option<Report> {
+        self.profile(&report, read);
+
+        if self.args.verify {
+            for (part, verdict) in self.answers.verify(&report, &self.args.parts()) {
+                println!(
+                    "Day {} Part {} ({}): {}",
+                    report.day, part, report.path, verdict
+                );
+
+                match verdict {
+                    Verdict::Pass => self.passed += 1,
+                    Verdict::Unrecorded => self.unrecorded += 1,
+                    _ => self.regressions += 1,
+                }
+            }
+
+            None
+        } else {
+            self.regressions += report
+                .outcomes
+                .iter()
+                .filter(|outcome| outcome.answer.is_err())
+                .count();
+
+            if self.args.record {
+                self.answers.record(&report);
+            }
+
+            Some(report)
+        }
+    }
+
+    fn profile(&self, report: &Report, read: profile::Phase) {
+        if !self.args.profile {
+            return;
+        }
+
+        eprintln!("Day {} profile ({}):", report.day, report.path);
+
+        for phase in std::iter::once(&read).chain(report.phases.iter()) {
+            eprintln!("    {}", phase);
+        }
+    }
+
+    /// Save any recorded answers and summarise the run.
+    pub fn finish(self) -> ExitCode {
+        if !self.reports.is_empty() {
+            print!("{}", self.args.output.render(&self.reports));
+        }
+
+        if self.args.record {
+            if let Err(error) = std::fs::write(&self.args.answers, self.answers.to_string()) {
+                eprintln!("{}: {}", self.args.answers, error);
+                return ExitCode::FAILURE;
+            }
+        }
+
+        if self.args.verify {
+            println!(
+                "{} passed, {} regressed, {} unrecorded",
+                self.passed, self.regressions, self.unrecorded
+            );
+        }
+
+        if self.regressions > 0 {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Command {
+        #[command(flatten)]
+        args: Args,
+    }
+
+    #[test]
+    fn merge_inputs() {
+        let command = Command::try_parse_from(["day_9", "-", "-i", "a.txt", "b.txt"])
+            .expect("Must accept inputs without '--input'");
+
+        assert_eq!(command.args.paths(9), ["a.txt", STDIN, "b.txt"]);
+
+        let command = Command::try_parse_from(["day_9"]).expect("Must parse arguments");
+
+        assert_eq!(command.args.paths(9), ["inputs/day_9.txt"]);
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::Display;
+
+/// Error produced while parsing or solving a puzzle.
+///
+/// Parsers report the slice of their input which caused the failure. Since
+/// every parser works on slices of the original puzzle input, the address of
+/// that slice is enough to recover its line and column once the error reaches
+/// the top level and is [located](Error::locate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    message: &'static str,
+    snippet: String,
+    address: Option<usize>,
+    location: Option<(usize, usize)>,
+    day: Option<u8>,
+    path: Option<String>,
+}
+
+impl Error {
+    /// Create an error caused by the provided snippet of the puzzle input.
+    pub fn new(message: &'static str, snippet: &str) -> Self {
+        Self {
+            message,
+            snippet: snippet.lines().next().unwrap_or_default().to_string(),
+            address: Some(snippet.as_ptr() as usize),
+            location: None,
+            day: None,
+            path: None,
+        }
+    }
+
+    /// Create an error caused by reaching the end of the provided snippet.
+    pub fn end_of(message: &'static str, snippet: &str) -> Self {
+        Self::new(message, &snippet[snippet.len()..])
+    }
+
+    /// Find the line and column of this error within the complete puzzle input.
+    ///
+    /// Errors whose snippet does not come from `input` are left unlocated.
+    pub fn locate(mut self, input: &str) -> Self {
+        let Some(offset) = self
+            .address
+            .and_then(|address| address.checked_sub(input.as_ptr() as usize))
+        else {
+            return self;
+        };
+
+        let Some(before) = input.get(..offset) else {
+            return self;
+        };
+
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+
+        self.location = Some((line, column));
+
+        self
+    }
+
+    /// Record the line and column of this error, for input which is read a
+    /// line at a time and so cannot be [located](Error::locate).
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.location = Some((line, column));
+        self
+    }
+
+    /// Record the day of the puzzle this error came from.
+    pub fn on_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Record the path of the input file this error came from.
+    pub fn in_file(mut self, path: &str) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    /// One-based line and column of the error within the puzzle input, if known.
+    pub fn location(&self) -> Option<(usize, usize)> {
+        self.location
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+}
+
+impl From<&'static str> for Error {
+    fn from(message: &'static str) -> Self {
+        Self {
+            message,
+            snippet: String::new(),
+            address: None,
+            location: None,
+            day: None,
+            path: None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.path, self.day) {
+            (Some(path), _) => write!(f, "{}", path)?,
+            (None, Some(day)) => write!(f, "day {}", day)?,
+            (None, None) => write!(f, "input")?,
+        }
+
+        if let Some((line, column)) = self.location {
+            write!(f, ":{}:{}", line, column)?;
+        }
+
+        write!(f, ": {}", self.message)?;
+
+        if !self.snippet.is_empty() {
+            write!(f, "\n    | {}", self.snippet)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_snippet() {
+        const INPUT: &str = "Card 1: 41 48 | 83 86\nCard 2: 13 xx | 61 30";
+
+        let snippet = &INPUT[INPUT.find("xx").unwrap()..][..2];
+
+        let error = Error::new("Could not parse winners", snippet)
+            .locate(INPUT)
+            .on_day(4)
+            .in_file("inputs/day_4.txt");
+
+        assert_eq!(error.location(), Some((2, 12)));
+        assert_eq!(
+            error.to_string(),
+            "inputs/day_4.txt:2:12: Could not parse winners\n    | xx"
+        );
+    }
+
+    #[test]
+    fn locate_end_of_line() {
+        const INPUT: &str = "seeds: 1 2\n\nseed-to-soil map:";
+
+        let line = INPUT.lines().nth(2).unwrap();
+
+        let error = Error::end_of("Missing rules", line).locate(INPUT);
+
+        assert_eq!(error.location(), Some((3, 18)));
+        assert_eq!(error.to_string(), "input:3:18: Missing rules");
+    }
+
+    #[test]
+    fn foreign_snippet_is_unlocated() {
+        const INPUT: &str = "Time: 7 15 30";
+
+        let joined = INPUT.split_ascii_whitespace().skip(1).collect::<String>();
+
+        let error = Error::new("Could not parse time", &joined)
+            .locate(INPUT)
+            .on_day(6);
+
+        assert_eq!(error.location(), None);
+        assert_eq!(
+            error.to_string(),
+            "day 6: Could not parse time\n    | 71530"
+        );
+
+        let error = error.at(1, 7).locate(INPUT);
+
+        assert_eq!(error.location(), Some((1, 7)));
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Year of Advent of Code these solutions are for.
+pub const YEAR: u16 = 2023;
+
+/// Environment variable checked for the AoC session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "github.com/bushrat011899/AoC2023 v",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Error produced while fetching a puzzle input.
+#[derive(Debug)]
+pub enum FetchError {
+    /// No session token was provided.
+    MissingSession,
+    /// The server responded with an unsuccessful status code.
+    Status(u16),
+    /// The request could not be sent, or its response could not be read.
+    Request(String),
+    /// The input could not be written to the cache.
+    Io(std::io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "No session token, set '{}' or provide a session file",
+                SESSION_VAR
+            ),
+            FetchError::Status(400) => write!(f, "Server rejected the session token (400)"),
+            FetchError::Status(404) => write!(f, "Puzzle input is not available yet (404)"),
+            FetchError::Status(status) => write!(f, "Server responded with status {}", status),
+            FetchError::Request(error) => write!(f, "Request failed: {}", error),
+            FetchError::Io(error) => write!(f, "Could not cache input: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<std::io::Error> for FetchError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Read the session token from the environment, falling back to the provided file.
+pub fn session(path: impl AsRef<Path>) -> Option<String> {
+    std::env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| std::fs::read_to_string(path).ok())
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+/// Downloads puzzle inputs into an on-disk cache.
+///
+/// Inputs are cached as `day_N.txt` and are never downloaded again once cached.
+/// Consecutive downloads are spaced out by at least the configured interval to
+/// keep the load on the AoC servers down.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    year: u16,
+    cache: PathBuf,
+    base_url: String,
+    session: Option<String>,
+    interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    /// Create a fetcher for the provided year, caching inputs in `cache`.
+    pub fn new(year: u16, cache: impl Into<PathBuf>) -> Self {
+        Self {
+            year,
+            cache: cache.into(),
+            base_url: BASE_URL.to_string(),
+            session: None,
+            interval: Duration::from_secs(5),
+            last_request: None,
+        }
+    }
+
+    /// Authenticate downloads with the provided session token.
+    pub fn with_session(mut self, session: Option<String>) -> Self {
+        self.session = session;
+        self
+    }
+
+    /// Download from a server other than the AoC website, such as a local stand-in.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Minimum time between consecutive downloads.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Path the input for the provided day is cached at.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.cache.join(format!("day_{}.txt", day))
+    }
+
+    /// Read the input for the provided day, downloading it if it isn't cached yet.
+    pub fn fetch(&mut self, day: u8) -> Result<String, FetchError> {
+        let path = self.path(day);
+
+        if let Ok(input) = std::fs::read_to_string(&path) {
+            return Ok(input);
+        }
+
+        let session = self.session.as_deref().ok_or(FetchError::MissingSession)?;
+
+        if let Some(elapsed) = self.last_request.map(|last| last.elapsed()) {
+            thread::sleep(self.interval.saturating_sub(elapsed));
+        }
+
+        self.last_request = Some(Instant::now());
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, self.year, day);
+
+        let input = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(status, _) => FetchError::Status(status),
+                error => FetchError::Request(error.to_string()),
+            })?
+            .into_string()
+            .map_err(|error| FetchError::Request(error.to_string()))?;
+
+        // Write to a temporary file first so an interrupted write is never cached
+        let partial = path.with_extension("txt.part");
+
+        std::fs::create_dir_all(&self.cache)?;
+        std::fs::write(&partial, &input)?;
+        std::fs::rename(&partial, &path)?;
+
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::{self, Receiver};
+
+    use super::*;
+
+    /// Start a stand-in AoC server responding to every request with the provided status and body.
+    ///
+    /// Returns the server's URL, and a receiver of the URL and cookie of each request.
+    fn serve(status: u16, body: &'static str) -> (String, Receiver<(String, String)>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").expect("Must be able to start server");
+        let url = format!("http://{}", server.server_addr());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string())
+                    .unwrap_or_default();
+
+                let _ = sender.send((request.url().to_string(), cookie));
+                let response = tiny_http::Response::from_string(body).with_status_code(status);
+                let _ = request.respond(response);
+            }
+        });
+
+        (url, receiver)
+    }
+
+    fn cache(name: &str) -> PathBuf {
+        let cache = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&cache);
+        cache
+    }
+
+    #[test]
+    fn fetch_and_cache() {
+        let (url, requests) = serve(200, "1abc2\n");
+        let cache = cache("fetch_and_cache");
+
+        let mut fetcher = Fetcher::new(2023, &cache)
+            .with_base_url(&url)
+            .with_session(Some("token".to_string()))
+            .with_interval(Duration::from_millis(100));
+
+        let start = Instant::now();
+
+        assert_eq!(fetcher.fetch(1).unwrap(), "1abc2\n");
+        assert_eq!(fetcher.fetch(1).unwrap(), "1abc2\n");
+        assert_eq!(fetcher.fetch(2).unwrap(), "1abc2\n");
+
+        assert!(start.elapsed() >= Duration::from_millis(100));
+        assert_eq!(
+            requests.try_iter().collect::<Vec<_>>(),
+            vec![
+                ("/2023/day/1/input".to_string(), "session=token".to_string()),
+                ("/2023/day/2/input".to_string(), "session=token".to_string()),
+            ]
+        );
+        assert_eq!(
+            std::fs::read_to_string(cache.join("day_1.txt")).unwrap(),
+            "1abc2\n"
+        );
+
+        let _ = std::fs::remove_dir_all(&cache);
+    }
+
+    #[test]
+    fn missing_session() {
+        let cache = cache("missing_session");
+        let mut fetcher = Fetcher::new(2023, &cache).with_base_url("http://127.0.0.1:9");
+
+        assert!(matches!(fetcher.fetch(1), Err(FetchError::MissingSession)));
+
+        std::fs::create_dir_all(&cache).unwrap();
+        std::fs::write(cache.join("day_1.txt"), "cached").unwrap();
+
+        assert_eq!(fetcher.fetch(1).unwrap(), "cached");
+
+        let _ = std::fs::remove_dir_all(&cache);
+    }
+
+    #[test]
+    fn failures_are_not_cached() {
+        let (url, _requests) = serve(404, "Not Found");
+        let cache = cache("failures_are_not_cached");
+
+        let mut fetcher = Fetcher::new(2023, &cache)
+            .with_base_url(&url)
+            .with_session(Some("token".to_string()));
+
+        assert!(matches!(fetcher.fetch(25), Err(FetchError::Status(404))));
+        assert!(!fetcher.path(25).exists());
+    }
+}
@@ -0,0 +1,353 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::Error;
+
+/// Position of a cell within a [`Grid`], as `(row, column)`.
+pub type Position = (usize, usize);
+
+/// One of the four cardinal directions, with north being towards row zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// Direction on the left when facing this direction.
+    pub fn left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    /// Direction on the right when facing this direction.
+    pub fn right(self) -> Self {
+        self.left().opposite()
+    }
+}
+
+/// Rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its rows, failing if they aren't all the same width.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, &'static str> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for row in rows {
+            if *width.get_or_insert(row.len()) != row.len() {
+                return Err("Rows must all be the same width");
+            }
+
+            height += 1;
+            cells.extend(row);
+        }
+
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.0 * self.width + position.1])
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// Every cell in the grid alongside its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Whether the position lies on the outermost edge of the grid.
+    pub fn is_edge(&self, (row, column): Position) -> bool {
+        self.contains((row, column))
+            && (row == 0 || column == 0 || row == self.height - 1 || column == self.width - 1)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        let cells = if column < self.width {
+            &self.cells[column..]
+        } else {
+            &[]
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Position one step away in the provided direction, if it is within the grid.
+    pub fn step(&self, (row, column): Position, direction: Direction) -> Option<Position> {
+        let position = match direction {
+            Direction::North => (row.checked_sub(1)?, column),
+            Direction::East => (row, column.checked_add(1)?),
+            Direction::South => (row.checked_add(1)?, column),
+            Direction::West => (row, column.checked_sub(1)?),
+        };
+
+        self.contains(position).then_some(position)
+    }
+
+    /// Positions of the up to 4 cells sharing an edge with the provided position.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Positions of the up to 8 cells sharing an edge or corner with the provided position.
+    pub fn neighbours_8(&self, (row, column): Position) -> impl Iterator<Item = Position> + '_ {
+        (-1isize..=1)
+            .flat_map(|dy| (-1isize..=1).map(move |dx| (dy, dx)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dy, dx)| {
+                Some((row.checked_add_signed(dy)?, column.checked_add_signed(dx)?))
+            })
+            .filter(|&position| self.contains(position))
+    }
+
+    /// Create a grid of the same size by applying `f` to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid of the provided size with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Swap rows with columns.
+    pub fn transpose(&self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position)
+            .expect("Position must be within the grid")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .expect("Position must be within the grid")
+    }
+}
+
+impl<T: TryFrom<char, Error = &'static str>> FromStr for Grid<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut width = None;
+
+        let rows = s
+            .lines()
+            .map(|line| {
+                let row = line
+                    .char_indices()
+                    .map(|(index, char)| {
+                        T::try_from(char).map_err(|message| {
+                            Error::new(message, &line[index..index + char.len_utf8()])
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if *width.get_or_insert(row.len()) != row.len() {
+                    return Err(Error::new("Rows must all be the same width", line));
+                }
+
+                Ok(row)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::from_rows(rows)?)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Cell(char);
+
+    impl TryFrom<char> for Cell {
+        type Error = &'static str;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '.' | '#' => Ok(Cell(value)),
+                _ => Err("Unknown cell"),
+            }
+        }
+    }
+
+    impl Display for Cell {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    const INPUT: &str = "#..\n.#.";
+
+    #[test]
+    fn parse_and_display() {
+        let grid = INPUT
+            .parse::<Grid<Cell>>()
+            .expect("Must be able to parse grid");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], Cell('#'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+        assert_eq!(grid.transpose().to_string(), "#.\n.#\n..");
+    }
+
+    #[test]
+    fn parse_errors() {
+        const INPUT: &str = "#..\n.x.";
+
+        let error = INPUT.parse::<Grid<Cell>>().unwrap_err().locate(INPUT);
+
+        assert_eq!(error.message(), "Unknown cell");
+        assert_eq!(error.location(), Some((2, 2)));
+
+        let error = "#..\n.#".parse::<Grid<Cell>>().unwrap_err();
+
+        assert_eq!(error.message(), "Rows must all be the same width");
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = INPUT
+            .parse::<Grid<Cell>>()
+            .expect("Must be able to parse grid");
+
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours_8((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+        );
+        assert_eq!(grid.step((1, 2), Direction::East), None);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = INPUT
+            .parse::<Grid<Cell>>()
+            .expect("Must be able to parse grid");
+
+        assert_eq!(grid.row(1), Some(&[Cell('.'), Cell('#'), Cell('.')][..]));
+        assert_eq!(
+            grid.column(2).copied().collect::<Vec<_>>(),
+            vec![Cell('.'), Cell('.')]
+        );
+        assert_eq!(grid.columns().count(), 3);
+        assert!(grid.is_edge((0, 1)) && !grid.is_edge((2, 1)));
+    }
+}
@@ -0,0 +1,56 @@
+use std::{fs::File, io, ops::Deref};
+
+use memmap2::Mmap;
+
+/// Puzzle input, either read into memory or memory-mapped from a file.
+pub(crate) enum Input {
+    Read(String),
+    Mapped(Mmap),
+}
+
+impl Input {
+    /// Memory-map a file, checking that it holds UTF-8 text.
+    pub(crate) fn map(path: &str) -> io::Result<Self> {
+        let file = File::open(path)?;
+
+        // SAFETY: input files are not expected to change while they are being solved.
+        let map = unsafe { Mmap::map(&file)? };
+
+        std::str::from_utf8(&map)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        Ok(Self::Mapped(map))
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            Self::Read(input) => input,
+            // SAFETY: mapped inputs are checked to be UTF-8 when created.
+            Self::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_file() {
+        let path = std::env::temp_dir().join(format!("aoc_map_{}.txt", std::process::id()));
+        std::fs::write(&path, "1abc2\npqr3stu8vwx\n").unwrap();
+
+        let input = Input::map(path.to_str().unwrap()).expect("Must be able to map file");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1abc2", "pqr3stu8vwx"]);
+
+        std::fs::write(&path, [0x66, 0xff]).unwrap();
+        let error = Input::map(path.to_str().unwrap()).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt::Display;
+
+mod answers;
+mod cli;
+mod error;
+mod grid;
+mod input;
+mod report;
+
+pub mod fetch;
+pub mod output;
+pub mod profile;
+pub mod settings;
+
+pub use answers::{Answers, Verdict};
+pub use cli::{Args, Runner};
+pub use error::Error;
+pub use grid::{Direction, Grid, Position};
+pub use output::Format;
+pub use report::{Outcome, Report};
+
+/// A solution to a single day of Advent of Code.
+pub trait Solution {
+    /// Day of the advent calendar this solution is for.
+    const DAY: u8;
+
+    /// Title of the puzzle, as given on the AoC website.
+    const TITLE: &'static str;
+
+    /// Example input given in the puzzle description.
+    const EXAMPLE: &'static str;
+
+    /// Puzzle input after parsing, shared by both parts.
+    type Input<'a>;
+
+    /// Answer produced by either part.
+    type Answer: Display;
+
+    /// Parse the raw puzzle input.
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error>;
+
+    /// Solve part 1 of the puzzle.
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, Error>;
+
+    /// Solve part 2 of the puzzle.
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, Error>;
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Solve a single part of the provided puzzle input with the default solution.
+pub fn solve<S: Solution + Default>(input: &str, part: Part) -> Result<S::Answer, Error> {
+    solve_with(&S::default(), input, part)
+}
+
+/// Solve a single part of the provided puzzle input.
+pub fn solve_with<S: Solution>(solution: &S, input: &str, part: Part) -> Result<S::Answer, Error> {
+    let parsed = solution
+        .parse(input)
+        .map_err(|error| error.locate(input).on_day(S::DAY))?;
+
+    match part {
+        Part::One => solution.part_1(&parsed),
+        Part::Two => solution.part_2(&parsed),
+    }
+    .map_err(|error| error.locate(input).on_day(S::DAY))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Sum";
+        const EXAMPLE: &'static str = "1\n2\n3";
+
+        type Input<'a> = Vec<&'a str>;
+        type Answer = u32;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+            Ok(input.lines().collect())
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+            input
+                .iter()
+                .map(|line| {
+                    line.parse::<u32>()
+                        .map_err(|_| Error::new("Not a number", line))
+                })
+                .sum()
+        }
+
+        fn part_2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+            Ok(input.len() as u32)
+        }
+    }
+
+    #[test]
+    fn solve_each_part() {
+        const INPUT: &str = "1\n2\n3";
+
+        assert_eq!(solve::<Sum>(INPUT, Part::One), Ok(6));
+        assert_eq!(solve::<Sum>(INPUT, Part::Two), Ok(3));
+    }
+
+    #[test]
+    fn solve_locates_errors() {
+        const INPUT: &str = "1\ntwo";
+
+        let error = solve::<Sum>(INPUT, Part::One).unwrap_err();
+
+        assert_eq!(error.location(), Some((2, 1)));
+        assert_eq!(error.day(), Some(0));
+    }
+}
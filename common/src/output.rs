@@ -0,0 +1,295 @@
+//! Rendering of reports, and helpers for days printing their own tables.
+
+use serde::Serialize;
+
+use crate::{Part, Report};
+
+/// Format reports are printed in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Human readable summary of each day
+    #[default]
+    Text,
+    /// JSON array with one object per part
+    Json,
+    /// CSV with a header row, then one row per part
+    Csv,
+}
+
+/// Machine-readable result of solving a single part.
+///
+/// Field names and order form the schema of the JSON and CSV output, so should
+/// only ever be added to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Row<'a> {
+    day: u8,
+    title: &'a str,
+    path: &'a str,
+    part: u8,
+    answer: Option<&'a str>,
+    parse_time_ns: u64,
+    solve_time_ns: Option<u64>,
+    error: Option<&'a str>,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+impl Row<'_> {
+    const HEADER: &'static str =
+        "day,title,path,part,answer,parse_time_ns,solve_time_ns,error,line,column";
+
+    fn csv(&self) -> String {
+        fn field<T: ToString>(value: Option<T>) -> String {
+            csv_field(&value.map(|value| value.to_string()).unwrap_or_default())
+        }
+
+        [
+            field(Some(self.day)),
+            field(Some(self.title)),
+            field(Some(self.path)),
+            field(Some(self.part)),
+            field(self.answer),
+            field(Some(self.parse_time_ns)),
+            field(self.solve_time_ns),
+            field(self.error),
+            field(self.line),
+            field(self.column),
+        ]
+        .join(",")
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break.
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Lay out cells in columns padded to the widest cell, separated by `|`.
+pub fn columns(header: &[String], rows: &[Vec<String>]) -> String {
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(column, title)| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(String::len)
+                .fold(title.len(), usize::max)
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = String::new();
+
+    for row in std::iter::once(header).chain(rows.iter().map(Vec::as_slice)) {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, &width)| format!("{:<width$}", cell))
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        table += line.trim_end();
+        table += "\n";
+    }
+
+    table
+}
+
+fn rows(reports: &[Report]) -> impl Iterator<Item = Row<'_>> {
+    reports.iter().flat_map(|report| {
+        report.outcomes.iter().map(|outcome| {
+            let error = outcome.answer.as_ref().err();
+            let location = error.and_then(|error| error.location());
+
+            Row {
+                day: report.day,
+                title: report.title,
+                path: &report.path,
+                part: match outcome.part {
+                    Part::One => 1,
+                    Part::Two => 2,
+                },
+                answer: outcome.answer.as_deref().ok(),
+                parse_time_ns: report.parse_time.as_nanos() as u64,
+                solve_time_ns: outcome.solve_time.map(|time| time.as_nanos() as u64),
+                error: error.map(|error| error.message()),
+                line: location.map(|(line, _)| line),
+                column: location.map(|(_, column)| column),
+            }
+        })
+    })
+}
+
+impl Format {
+    /// Render the provided reports in this format.
+    pub fn render(self, reports: &[Report]) -> String {
+        match self {
+            Format::Text => reports.iter().map(|report| report.to_string()).collect(),
+            Format::Json => {
+                let rows = rows(reports).collect::<Vec<_>>();
+
+                serde_json::to_string_pretty(&rows).expect("Rows must serialize") + "\n"
+            }
+            Format::Csv => std::iter::once(Row::HEADER.to_string())
+                .chain(rows(reports).map(|row| row.csv()))
+                .map(|line| line + "\n")
+                .collect(),
+        }
+    }
+}
+
+/// Render reports for several input files of the same day as a table, with
+/// one row per file.
+///
+/// Errors are marked in the table, then listed in full beneath it.
+pub(crate) fn table(reports: &[Report]) -> String {
+    let Some(first) = reports.first() else {
+        return String::new();
+    };
+
+    let header = std::iter::once("Input".to_string())
+        .chain(
+            first
+                .outcomes
+                .iter()
+                .map(|outcome| format!("Part {}", outcome.part)),
+        )
+        .collect::<Vec<_>>();
+
+    let rows = reports
+        .iter()
+        .map(|report| {
+            std::iter::once(report.path.clone())
+                .chain(report.outcomes.iter().map(|outcome| match &outcome.answer {
+                    Ok(answer) => answer.clone(),
+                    Err(_) => "error".to_string(),
+                }))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = format!("--- Day {}: {} ---\n", first.day, first.title);
+
+    table += &columns(&header, &rows);
+
+    for report in reports {
+        let mut errors = report
+            .outcomes
+            .iter()
+            .filter_map(|outcome| outcome.answer.as_ref().err())
+            .collect::<Vec<_>>();
+
+        // A parse error is shared by every part
+        errors.dedup();
+
+        for error in errors {
+            table += &format!("{}\n", error);
+        }
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{Error, Outcome};
+
+    fn report() -> Report {
+        const INPUT: &str = "Card 1: 41 48 | 83 86\nCard 2: 13 xx | 61 30";
+
+        let error = Error::new("Could not parse winners, \"xx\"", &INPUT[33..35])
+            .locate(INPUT)
+            .on_day(4);
+
+        Report {
+            day: 4,
+            title: "Scratchcards",
+            path: "inputs/day_4.txt".to_string(),
+            parse_time: Duration::from_nanos(1500),
+            outcomes: vec![
+                Outcome {
+                    part: Part::One,
+                    answer: Ok("13".to_string()),
+                    solve_time: Some(Duration::from_nanos(250)),
+                },
+                Outcome {
+                    part: Part::Two,
+                    answer: Err(error),
+                    solve_time: Some(Duration::from_nanos(100)),
+                },
+            ],
+            phases: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn render_json() {
+        let json = Format::Json.render(&[report()]);
+        let rows = serde_json::from_str::<serde_json::Value>(&json).expect("Must be valid JSON");
+
+        assert_eq!(
+            rows,
+            serde_json::json!([
+                {
+                    "day": 4,
+                    "title": "Scratchcards",
+                    "path": "inputs/day_4.txt",
+                    "part": 1,
+                    "answer": "13",
+                    "parse_time_ns": 1500,
+                    "solve_time_ns": 250,
+                    "error": null,
+                    "line": null,
+                    "column": null
+                },
+                {
+                    "day": 4,
+                    "title": "Scratchcards",
+                    "path": "inputs/day_4.txt",
+                    "part": 2,
+                    "answer": null,
+                    "parse_time_ns": 1500,
+                    "solve_time_ns": 100,
+                    "error": "Could not parse winners, \"xx\"",
+                    "line": 2,
+                    "column": 12
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn render_table() {
+        let mut alice = report();
+        alice.path = "inputs/alice/day_4.txt".to_string();
+        alice.outcomes[1].answer = Ok("30".to_string());
+
+        assert_eq!(
+            table(&[alice, report()]),
+            r#"--- Day 4: Scratchcards ---
+Input                  | Part 1 | Part 2
+inputs/alice/day_4.txt | 13     | 30
+inputs/day_4.txt       | 13     | error
+day 4:2:12: Could not parse winners, "xx"
+    | xx
+"#
+        );
+    }
+
+    #[test]
+    fn render_csv() {
+        assert_eq!(
+            Format::Csv.render(&[report()]),
+            r#"day,title,path,part,answer,parse_time_ns,solve_time_ns,error,line,column
+4,Scratchcards,inputs/day_4.txt,1,13,1500,250,,,
+4,Scratchcards,inputs/day_4.txt,2,,1500,100,"Could not parse winners, ""xx""",2,12
+"#
+        );
+    }
+}
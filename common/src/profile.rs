@@ -0,0 +1,243 @@
+//! Opt-in instrumentation of the time, memory and day-specific work spent on
+//! each phase of solving a puzzle.
+//!
+//! Nothing is recorded until profiling is [enabled](enable), so counters and
+//! the [`Allocator`] cost a single atomic load otherwise.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::{
+        atomic::{AtomicBool, AtomicIsize, AtomicU64, Ordering},
+        Mutex, Once,
+    },
+    time::{Duration, Instant},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static COUNTERS: Mutex<Vec<&'static Counter>> = Mutex::new(Vec::new());
+
+static TRACKING: AtomicBool = AtomicBool::new(false);
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// Start recording counters and memory usage.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Number of times a day-specific event happened, such as recursive calls or map lookups.
+///
+/// ```
+/// static LOOKUPS: common::profile::Counter = common::profile::Counter::new("map lookups");
+///
+/// LOOKUPS.increment();
+/// ```
+pub struct Counter {
+    name: &'static str,
+    value: AtomicU64,
+    registered: Once,
+}
+
+impl Counter {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            value: AtomicU64::new(0),
+            registered: Once::new(),
+        }
+    }
+
+    pub fn add(&'static self, count: u64) {
+        if !is_enabled() {
+            return;
+        }
+
+        self.registered
+            .call_once(|| COUNTERS.lock().unwrap().push(self));
+        self.value.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn increment(&'static self) {
+        self.add(1);
+    }
+}
+
+/// Reset every counter, returning the non-zero counts.
+fn take_counters() -> Vec<(&'static str, u64)> {
+    COUNTERS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|counter| (counter.name, counter.value.swap(0, Ordering::Relaxed)))
+        .filter(|&(_, count)| count > 0)
+        .collect()
+}
+
+/// Global allocator which tracks peak heap usage while profiling.
+///
+/// Binaries opt in with:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: common::profile::Allocator = common::profile::Allocator;
+/// ```
+pub struct Allocator;
+
+impl Allocator {
+    fn track(delta: isize) {
+        if !is_enabled() {
+            return;
+        }
+
+        TRACKING.store(true, Ordering::Relaxed);
+
+        let allocated = ALLOCATED.fetch_add(delta, Ordering::Relaxed) + delta;
+        PEAK.fetch_max(allocated, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Allocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            Self::track(layout.size() as isize);
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            Self::track(layout.size() as isize);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::track(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        if !new_ptr.is_null() {
+            Self::track(new_size as isize - layout.size() as isize);
+        }
+
+        new_ptr
+    }
+}
+
+/// Resources used by a single phase of solving a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Phase {
+    pub name: &'static str,
+    pub time: Duration,
+    /// Most heap memory allocated at once during the phase, if the [`Allocator`] is in use.
+    pub peak_memory: Option<usize>,
+    pub counters: Vec<(&'static str, u64)>,
+}
+
+/// Run the provided phase, measuring the resources it used.
+pub fn measure<T>(name: &'static str, phase: impl FnOnce() -> T) -> (T, Phase) {
+    take_counters();
+
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(allocated, Ordering::Relaxed);
+
+    let start = Instant::now();
+    let value = phase();
+    let time = start.elapsed();
+
+    let peak_memory = TRACKING
+        .load(Ordering::Relaxed)
+        .then(|| (PEAK.load(Ordering::Relaxed) - allocated).max(0) as usize);
+
+    let phase = Phase {
+        name,
+        time,
+        peak_memory,
+        counters: take_counters(),
+    };
+
+    (value, phase)
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:<8} {:>12}", self.name, format!("{:.2?}", self.time))?;
+
+        if let Some(peak) = self.peak_memory {
+            write!(f, "  peak {:>10}", bytes(peak))?;
+        }
+
+        for (name, count) in self.counters.iter() {
+            write!(f, "  {}: {}", name, count)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static CALLS: Counter = Counter::new("calls");
+
+    #[test]
+    fn measure_counters() {
+        enable();
+
+        let (value, phase) = measure("part 1", || {
+            for _ in 0..3 {
+                CALLS.increment();
+            }
+
+            7
+        });
+
+        assert_eq!(value, 7);
+        assert_eq!(phase.name, "part 1");
+        assert_eq!(phase.counters, vec![("calls", 3)]);
+
+        let (_, phase) = measure("part 2", || ());
+
+        assert_eq!(phase.counters, vec![]);
+    }
+
+    #[test]
+    fn format_bytes() {
+        assert_eq!(bytes(512), "512 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,112 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{
+    profile::{self, Phase},
+    Error, Part, Solution,
+};
+
+/// Answer to a single part of a puzzle, formatted for display.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub part: Part,
+    pub answer: Result<String, Error>,
+    /// Time taken to solve this part, if parsing succeeded and the part was
+    /// solved separately from parsing.
+    pub solve_time: Option<Duration>,
+}
+
+/// Answers to the requested parts of a puzzle for a single input file.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub title: &'static str,
+    pub path: String,
+    pub parse_time: Duration,
+    pub outcomes: Vec<Outcome>,
+    /// Resources used by each phase, in the order they ran.
+    pub phases: Vec<Phase>,
+}
+
+impl Report {
+    /// Parse the provided puzzle input and solve the requested parts.
+    ///
+    /// Errors are located within `input` and reported against `path`, the file
+    /// the input was read from.
+    pub fn new<S: Solution>(solution: &S, path: &str, input: &str, parts: &[Part]) -> Self {
+        let locate = |error: Error| error.locate(input).on_day(S::DAY).in_file(path);
+
+        let (parsed, parse) = profile::measure("parse", || solution.parse(input));
+        let parse_time = parse.time;
+        let mut phases = vec![parse];
+
+        let outcomes = match parsed {
+            Ok(parsed) => parts
+                .iter()
+                .map(|&part| {
+                    let name = match part {
+                        Part::One => "part 1",
+                        Part::Two => "part 2",
+                    };
+
+                    let (answer, phase) = profile::measure(name, || match part {
+                        Part::One => solution.part_1(&parsed),
+                        Part::Two => solution.part_2(&parsed),
+                    });
+                    let solve_time = phase.time;
+
+                    phases.push(phase);
+
+                    Outcome {
+                        part,
+                        answer: answer.map(|answer| answer.to_string()).map_err(locate),
+                        solve_time: Some(solve_time),
+                    }
+                })
+                .collect(),
+            Err(error) => {
+                let error = locate(error);
+
+                parts
+                    .iter()
+                    .map(|&part| Outcome {
+                        part,
+                        answer: Err(error.clone()),
+                        solve_time: None,
+                    })
+                    .collect()
+            }
+        };
+
+        Self {
+            day: S::DAY,
+            title: S::TITLE,
+            path: path.to_string(),
+            parse_time,
+            outcomes,
+            phases,
+        }
+    }
+
+    /// Answer to the provided part, if it was requested.
+    pub fn answer(&self, part: Part) -> Option<&Result<String, Error>> {
+        self.outcomes
+            .iter()
+            .find(|outcome| outcome.part == part)
+            .map(|outcome| &outcome.answer)
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "--- Day {}: {} ---", self.day, self.title)?;
+
+        for outcome in self.outcomes.iter() {
+            match &outcome.answer {
+                Ok(answer) => writeln!(f, "Part {}: {}", outcome.part, answer)?,
+                Err(error) => writeln!(f, "Part {}: {}", outcome.part, error)?,
+            }
+        }
+
+        Ok(())
+    }
+}
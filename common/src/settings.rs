@@ -0,0 +1,105 @@
+//! `key=value` settings given on the command line or read from files.
+//!
+//! Settings are separated by commas or newlines, with spaces around them, blank
+//! lines and lines starting with `#` ignored:
+//!
+//! ```text
+//! # Puzzle bag
+//! red=12, green=13
+//! blue = 14
+//! ```
+//!
+//! A comma preceded by a backslash, `\,`, belongs to its key or value instead
+//! of separating settings, and is read back as a plain comma by [`unescape`].
+
+use std::{borrow::Cow, str::FromStr};
+
+use crate::Error;
+
+/// Split settings into their keys and values, as slices of `s` which may still
+/// contain escaped commas.
+///
+/// `expected` describes the form of a setting, and is the message of the error
+/// for any setting without an `=`.
+pub fn settings<'a>(
+    s: &'a str,
+    expected: &'static str,
+) -> impl Iterator<Item = Result<(&'a str, &'a str), Error>> + 'a {
+    s.lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(|line| {
+            let mut escaped = false;
+
+            line.split(move |char| {
+                let split = char == ',' && !escaped;
+                escaped = char == '\\';
+                split
+            })
+        })
+        .map(str::trim)
+        .filter(|setting| !setting.is_empty())
+        .map(move |setting| self::setting(setting, expected))
+}
+
+/// Split a single setting into its trimmed key and value.
+pub fn setting<'a>(s: &'a str, expected: &'static str) -> Result<(&'a str, &'a str), Error> {
+    let (key, value) = s.split_once('=').ok_or_else(|| Error::new(expected, s))?;
+
+    Ok((key.trim(), value.trim()))
+}
+
+/// Replace escaped commas in a key or value with plain commas.
+pub fn unescape(text: &str) -> Cow<'_, str> {
+    if text.contains("\\,") {
+        Cow::Owned(text.replace("\\,", ","))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Describe an error in a command line argument, as reported by clap.
+pub fn describe(error: Error) -> String {
+    format!("{}: '{}'", error.message(), error.snippet())
+}
+
+/// Parse a command line argument, for use as a clap value parser.
+pub fn parse_argument<T: FromStr<Err = Error>>(s: &str) -> Result<T, String> {
+    s.parse().map_err(describe)
+}
+
+/// Read and parse a settings file, reporting errors with their location.
+pub fn load<T: FromStr<Err = Error>>(path: &str) -> Result<T, String> {
+    let text = std::fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+
+    text.parse()
+        .map_err(|error: Error| error.locate(&text).in_file(path).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_settings() {
+        const INPUT: &str = "# Comment\na=1, b = 2\n\n c=x\\,y ,d=";
+
+        let settings = settings(INPUT, "Expected 'key=value'")
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Must split settings");
+
+        assert_eq!(
+            settings,
+            [("a", "1"), ("b", "2"), ("c", "x\\,y"), ("d", "")]
+        );
+        assert_eq!(unescape(settings[2].1), "x,y");
+
+        const INVALID: &str = "a=1\nb";
+
+        let error = super::settings(INVALID, "Expected 'key=value'")
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err()
+            .locate(INVALID);
+
+        assert_eq!(error.location(), Some((2, 1)));
+    }
+}
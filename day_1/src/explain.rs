@@ -0,0 +1,209 @@
+use std::fmt::Write;
+
+use crate::{Found, Reader};
+
+const ANSI_FIRST: &str = "\x1b[1;32m";
+const ANSI_LAST: &str = "\x1b[1;34m";
+const ANSI_BOTH: &str = "\x1b[1;35m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// How the matched words are marked in an explanation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// The first word in `[]` and the last in `{}`.
+    Brackets,
+    /// The first word in green, the last in blue, and any overlap in magenta.
+    Ansi,
+}
+
+/// Describe the words picked from each line and the calibration value they form.
+///
+/// Lines without a digit are listed in a closing summary rather than failing.
+/// When the reader rewrites lines, the rewritten text is shown after the original.
+pub fn explain(reader: &Reader, input: &str, highlight: Highlight) -> String {
+    let mut buffer = Vec::new();
+    let mut output = String::new();
+    let mut missing = Vec::new();
+    let mut sum = 0u128;
+
+    let lines = input.lines().count();
+    let width = lines.to_string().len();
+
+    for (number, line) in (1..).zip(input.lines()) {
+        let text = reader.rewrite(line);
+        let shown = if text == line {
+            String::new()
+        } else {
+            format!("{} => ", line)
+        };
+
+        let Some((first, last)) = reader.find(&text, &mut buffer) else {
+            missing.push(number);
+            let _ = writeln!(output, "{:>width$}: {}{}  no digit", number, shown, text);
+            continue;
+        };
+
+        let value = 10 * first.first_digit() + last.last_digit();
+        sum += value as u128;
+
+        let _ = writeln!(
+            output,
+            "{:>width$}: {}{}  first {:?} at {} = {}, last {:?} at {} = {} -> {}",
+            number,
+            shown,
+            mark(&text, first, last, highlight),
+            &text[first.start..first.end],
+            first.start,
+            first.first_digit(),
+            &text[last.start..last.end],
+            last.start,
+            last.last_digit(),
+            value,
+        );
+    }
+
+    let _ = writeln!(output, "{} lines, sum {}", lines, sum);
+
+    if !missing.is_empty() {
+        let numbers = missing
+            .iter()
+            .map(|number| number.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let _ = writeln!(
+            output,
+            "{} line{} without a digit: {}",
+            missing.len(),
+            if missing.len() == 1 { "" } else { "s" },
+            numbers
+        );
+    }
+
+    output
+}
+
+/// The line with the first and last words marked.
+fn mark(line: &str, first: Found, last: Found, highlight: Highlight) -> String {
+    let same = first == last;
+    let mut marked = String::with_capacity(line.len() + 16);
+    let mut style = None;
+
+    let boundaries = line
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(std::iter::once(line.len()));
+
+    for index in boundaries {
+        match highlight {
+            Highlight::Brackets => {
+                if index == first.end {
+                    marked.push(']');
+                }
+                if !same && index == last.end {
+                    marked.push('}');
+                }
+                if index == first.start {
+                    marked.push('[');
+                }
+                if !same && index == last.start {
+                    marked.push('{');
+                }
+            }
+            Highlight::Ansi => {
+                let in_first = (first.start..first.end).contains(&index);
+                let in_last = (last.start..last.end).contains(&index);
+
+                let next = match (in_first, in_last) {
+                    (true, true) if !same => Some(ANSI_BOTH),
+                    (true, _) => Some(ANSI_FIRST),
+                    (false, true) => Some(ANSI_LAST),
+                    (false, false) => None,
+                };
+
+                if next != style {
+                    if style.is_some() {
+                        marked.push_str(ANSI_RESET);
+                    }
+                    if let Some(next) = next {
+                        marked.push_str(next);
+                    }
+                    style = next;
+                }
+            }
+        }
+
+        if let Some(char) = line[index..].chars().next() {
+            marked.push(char);
+        }
+    }
+
+    marked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Lexicon, Strategy};
+
+    #[test]
+    fn explain_brackets() {
+        const INPUT: &str = r#"two1nine
+eightwothree
+pqrstuvwx
+7pqrstsixteen"#;
+        const RESULT: &str = r#"1: [two]1{nine}  first "two" at 0 = 2, last "nine" at 4 = 9 -> 29
+2: [eight]wo{three}  first "eight" at 0 = 8, last "three" at 7 = 3 -> 83
+3: pqrstuvwx  no digit
+4: [7]pqrst{six}teen  first "7" at 0 = 7, last "six" at 6 = 6 -> 76
+4 lines, sum 188
+1 line without a digit: 3
+"#;
+
+        let reader = Reader::new(
+            &Lexicon::digits(),
+            &Lexicon::english(),
+            Strategy::Overlapping,
+        );
+
+        assert_eq!(explain(&reader, INPUT, Highlight::Brackets), RESULT);
+    }
+
+    #[test]
+    fn explain_replaced() {
+        const INPUT: &str = "eightwothree";
+        const RESULT: &str = r#"1: eightwothree => eigh[2]{3}  first "2" at 4 = 2, last "3" at 5 = 3 -> 23
+1 lines, sum 23
+"#;
+
+        let reader = Reader::new(
+            &Lexicon::digits(),
+            &Lexicon::english(),
+            Strategy::ReplaceThenScan,
+        );
+
+        assert_eq!(explain(&reader, INPUT, Highlight::Brackets), RESULT);
+    }
+
+    #[test]
+    fn mark_overlap() {
+        let mut lexicon = Lexicon::digits();
+        lexicon.extend(&Lexicon::english());
+
+        let scanner = lexicon.scanner();
+        let mut buffer = Vec::new();
+
+        let (first, last) = scanner.find("eightwo", &mut buffer).unwrap();
+        assert_eq!(
+            mark("eightwo", first, last, Highlight::Brackets),
+            "[eigh{t]wo}"
+        );
+
+        let (first, last) = scanner.find("x2y", &mut buffer).unwrap();
+        assert_eq!(mark("x2y", first, last, Highlight::Brackets), "x[2]y");
+        assert_eq!(
+            mark("x2y", first, last, Highlight::Ansi),
+            format!("x{}2{}y", ANSI_FIRST, ANSI_RESET)
+        );
+    }
+}
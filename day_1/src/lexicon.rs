@@ -0,0 +1,223 @@
+use std::str::FromStr;
+
+use common::{settings, Error};
+
+use crate::Scanner;
+
+/// First code point of each run of Unicode decimal digits recognised by
+/// [`Lexicon::unicode_digits`].
+const UNICODE_ZEROES: [char; 6] = [
+    '\u{0660}', // Arabic-Indic
+    '\u{06F0}', // Extended Arabic-Indic
+    '\u{0966}', // Devanagari
+    '\u{09E6}', // Bengali
+    '\u{0E50}', // Thai
+    '\u{FF10}', // Full-width
+];
+
+const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Words which may be written in a calibration line, and the numbers they stand for.
+///
+/// A word standing for a multi-digit number contributes its leading digit when
+/// it is the first in a line, and its final digit when it is the last, so
+/// "twenty" reads as 2 or 0 respectively.
+///
+/// Lexicon files contain `word = number` [settings](common::settings):
+///
+/// ```text
+/// # French
+/// un = 1
+/// deux = 2, trois = 3
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Lexicon {
+    entries: Vec<(String, u32)>,
+}
+
+impl Lexicon {
+    /// The ASCII digits `0` to `9`.
+    pub fn digits() -> Self {
+        Self {
+            entries: (0..10).map(|digit| (digit.to_string(), digit)).collect(),
+        }
+    }
+
+    /// Decimal digits from several other scripts, such as Arabic-Indic and full-width digits.
+    pub fn unicode_digits() -> Self {
+        let entries = UNICODE_ZEROES
+            .iter()
+            .flat_map(|&zero| {
+                (0..10).filter_map(move |digit| {
+                    char::from_u32(zero as u32 + digit).map(|char| (char.to_string(), digit))
+                })
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// The English words "zero" to "nine".
+    pub fn english() -> Self {
+        Self {
+            entries: (0..)
+                .zip(ENGLISH)
+                .map(|(value, word)| (word.to_string(), value))
+                .collect(),
+        }
+    }
+
+    pub fn insert(&mut self, word: impl Into<String>, value: u32) {
+        self.entries.push((word.into(), value));
+    }
+
+    pub fn extend(&mut self, other: &Lexicon) {
+        self.entries.extend(other.entries.iter().cloned());
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.entries
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Build a scanner finding any word in this lexicon.
+    pub fn scanner(&self) -> Scanner {
+        Scanner::new(self.entries())
+    }
+}
+
+/// Parse a single `word = number` lexicon entry.
+pub fn parse_entry(s: &str) -> Result<(String, u32), Error> {
+    let (word, value) = settings::setting(s, "Expected 'word = number'")?;
+
+    entry(s, word, value)
+}
+
+fn entry(s: &str, word: &str, value: &str) -> Result<(String, u32), Error> {
+    if word.is_empty() {
+        return Err(Error::new("Missing word", s));
+    }
+
+    let value = value
+        .parse()
+        .map_err(|_| Error::new("Could not parse number", value))?;
+
+    Ok((settings::unescape(word).into_owned(), value))
+}
+
+impl FromStr for Lexicon {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = settings::settings(s, "Expected 'word = number'")
+            .map(|setting| {
+                let (word, value) = setting?;
+                entry(word, word, value)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lexicon() {
+        const INPUT: &str = "# German\neins = 1\n\nzwei=2, zwanzig = 20\na\\,b = 3";
+
+        let lexicon = INPUT
+            .parse::<Lexicon>()
+            .expect("Must be able to parse lexicon");
+
+        assert_eq!(
+            lexicon.entries().collect::<Vec<_>>(),
+            vec![("eins", 1), ("zwei", 2), ("zwanzig", 20), ("a,b", 3)]
+        );
+
+        let error = "eins = one"
+            .parse::<Lexicon>()
+            .unwrap_err()
+            .locate("eins = one");
+
+        assert_eq!(error.message(), "Could not parse number");
+        assert_eq!(error.location(), Some((1, 8)));
+    }
+
+    #[test]
+    fn unicode_digits() {
+        let mut lexicon = Lexicon::digits();
+        lexicon.extend(&Lexicon::unicode_digits());
+
+        let scanner = lexicon.scanner();
+        let mut buffer = Vec::new();
+
+        assert_eq!(scanner.first_and_last("a٣b٧c", &mut buffer), Some((3, 7)));
+        assert_eq!(scanner.first_and_last("１x9", &mut buffer), Some((1, 9)));
+    }
+
+    #[test]
+    fn multi_digit_words() {
+        let mut lexicon = Lexicon::digits();
+        lexicon.insert("twenty", 20);
+        lexicon.insert("eleven", 11);
+
+        let scanner = lexicon.scanner();
+        let mut buffer = Vec::new();
+
+        assert_eq!(
+            scanner.first_and_last("twentyx5", &mut buffer),
+            Some((2, 5))
+        );
+        assert_eq!(scanner.first_and_last("3twenty", &mut buffer), Some((3, 0)));
+        assert_eq!(scanner.first_and_last("eleven", &mut buffer), Some((1, 1)));
+    }
+
+    #[test]
+    fn multi_digit_prefixes() {
+        let mut lexicon = Lexicon::english();
+        lexicon.insert("sixteen", 16);
+        lexicon.insert("seventeen", 17);
+
+        let scanner = lexicon.scanner();
+        let mut buffer = Vec::new();
+
+        assert_eq!(
+            scanner.first_and_last("xsixteen", &mut buffer),
+            Some((1, 6))
+        );
+        assert_eq!(
+            scanner.first_and_last("sixteenx", &mut buffer),
+            Some((1, 6))
+        );
+        assert_eq!(
+            scanner.first_and_last("seventeen", &mut buffer),
+            Some((1, 7))
+        );
+        assert_eq!(
+            scanner.first_and_last("sixseven", &mut buffer),
+            Some((6, 7))
+        );
+        assert_eq!(
+            scanner
+                .find_non_overlapping("seventeensix")
+                .map(|(first, last)| (first.value, last.value)),
+            Some((17, 6))
+        );
+        assert_eq!(
+            scanner
+                .find_non_overlapping("xsixteen")
+                .map(|(first, last)| (first.value, last.value)),
+            Some((16, 16))
+        );
+    }
+}
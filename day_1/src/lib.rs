@@ -0,0 +1,234 @@
+use common::{Error, Part, Solution};
+
+mod explain;
+mod lexicon;
+mod parallel;
+mod scanner;
+mod strategy;
+
+pub use explain::{explain, Highlight};
+pub use lexicon::{parse_entry, Lexicon};
+pub use parallel::{calibration_sum_parallel, chunks, CHUNK_SIZE};
+pub use scanner::{Found, Scanner};
+pub use strategy::{Reader, Strategy};
+
+/// Calibration value extractor, recognising the entries of its lexicons.
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    /// Digits recognised by both parts.
+    pub digits: Lexicon,
+    /// Words recognised by part 2 alongside the digits.
+    pub words: Lexicon,
+    /// How part 2 reads words sharing letters.
+    pub strategy: Strategy,
+    /// Sum chunks of the input on several threads.
+    pub parallel: bool,
+}
+
+impl Default for Puzzle {
+    fn default() -> Self {
+        Self {
+            digits: Lexicon::digits(),
+            words: Lexicon::english(),
+            strategy: Strategy::default(),
+            parallel: false,
+        }
+    }
+}
+
+impl Puzzle {
+    fn sum(&self, part: Part, input: &str) -> Result<u128, Error> {
+        let reader = self.reader(part);
+
+        if self.parallel {
+            calibration_sum_parallel(&reader, input, CHUNK_SIZE)
+        } else {
+            calibration_sum(&reader, input)
+        }
+    }
+
+    /// Reader recognising the entries used by a part.
+    pub fn reader(&self, part: Part) -> Reader {
+        match part {
+            Part::One => Reader::new(&self.digits, &Lexicon::default(), Strategy::Overlapping),
+            Part::Two => Reader::new(&self.digits, &self.words, self.strategy),
+        }
+    }
+}
+
+impl Solution for Puzzle {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+    const EXAMPLE: &'static str = r#"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet"#;
+
+    type Input<'a> = &'a str;
+    type Answer = u128;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        Ok(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        self.sum(Part::One, input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        self.sum(Part::Two, input)
+    }
+}
+
+/// Sum of the calibration values formed by the first and last digit of each line.
+fn calibration_sum(reader: &Reader, input: &str) -> Result<u128, Error> {
+    let mut buffer = Vec::new();
+    let mut result = 0;
+
+    for line in input.lines() {
+        let (first, last) = reader
+            .first_and_last(line, &mut buffer)
+            .ok_or_else(|| Error::new("Could not find digit in string", line))?;
+        result += (10 * first + last) as u128;
+    }
+
+    Ok(result)
+}
+
+pub fn parse_part_1(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn parse_part_2(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1() {
+        let input = r#"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet"#;
+
+        assert_eq!(parse_part_1(input), Some(142));
+    }
+
+    #[test]
+    fn example_2() {
+        let input = r#"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"#;
+
+        assert_eq!(parse_part_2(input), Some(281));
+    }
+
+    #[test]
+    fn example_2_1() {
+        let input = r#"two1nine"#;
+
+        assert_eq!(parse_part_2(input), Some(29));
+    }
+
+    #[test]
+    fn example_2_2() {
+        let input = r#"eightwothree"#;
+
+        assert_eq!(parse_part_2(input), Some(83));
+    }
+
+    #[test]
+    fn example_2_3() {
+        let input = r#"abcone2threexyz"#;
+
+        assert_eq!(parse_part_2(input), Some(13));
+    }
+
+    #[test]
+    fn example_2_4() {
+        let input = r#"xtwone3four"#;
+
+        assert_eq!(parse_part_2(input), Some(24));
+    }
+
+    #[test]
+    fn example_2_5() {
+        let input = r#"4nineeightseven2"#;
+
+        assert_eq!(parse_part_2(input), Some(42));
+    }
+
+    #[test]
+    fn example_2_6() {
+        let input = r#"zoneight234"#;
+
+        assert_eq!(parse_part_2(input), Some(14));
+    }
+
+    #[test]
+    fn example_2_7() {
+        let input = r#"7pqrstsixteen"#;
+
+        assert_eq!(parse_part_2(input), Some(76));
+    }
+
+    #[test]
+    fn missing_digit() {
+        let input = r#"1abc2
+pqrstuvwx"#;
+
+        let error = common::solve::<Puzzle>(input, Part::Two).unwrap_err();
+
+        assert_eq!(error.message(), "Could not find digit in string");
+        assert_eq!(error.location(), Some((2, 1)));
+    }
+
+    #[test]
+    fn french_lexicon() {
+        const INPUT: &str = r#"undeuxtrois
+xquatre7"#;
+
+        let puzzle = Puzzle {
+            words: "un = 1\ndeux = 2\ntrois = 3\nquatre = 4"
+                .parse()
+                .expect("Must be able to parse lexicon"),
+            ..Puzzle::default()
+        };
+
+        assert_eq!(common::solve_with(&puzzle, INPUT, Part::Two), Ok(13 + 47));
+    }
+
+    #[test]
+    fn overlap_strategies() {
+        const INPUT: &str = r#"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"#;
+
+        let solve = |strategy| {
+            let puzzle = Puzzle {
+                strategy,
+                ..Puzzle::default()
+            };
+
+            common::solve_with(&puzzle, INPUT, Part::Two)
+        };
+
+        assert_eq!(solve(Strategy::Overlapping), Ok(281));
+        assert_eq!(solve(Strategy::GreedyNonOverlapping), Ok(281));
+        assert_eq!(
+            solve(Strategy::ReplaceThenScan),
+            Ok(29 + 23 + 13 + 14 + 42 + 14 + 76)
+        );
+    }
+}
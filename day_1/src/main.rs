@@ -1,199 +1,113 @@
-use clap::Parser;
+use std::{io::IsTerminal, process::ExitCode};
+
+use clap::{Parser, ValueEnum};
+use common::{Part, Runner, Solution};
+use day_1::{Highlight, Lexicon, Puzzle, Strategy};
+
+#[global_allocator]
+static ALLOCATOR: common::profile::Allocator = common::profile::Allocator;
 
 /// Command arguments
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Input file from AoC
-    #[arg(short, long, default_value_t = String::from("inputs/day_1.txt"))]
-    input: String,
-}
+    #[command(flatten)]
+    common: common::Args,
 
-fn main() {
-    let args = Args::parse();
+    /// File of 'word = number' entries used by part 2 instead of the English digit words
+    #[arg(long)]
+    lexicon: Option<String>,
 
-    let input = std::fs::read_to_string(args.input).expect("must be able to read input file");
+    /// Extra 'word=number' entry recognised by part 2
+    #[arg(long = "word", value_parser = parse_word)]
+    words: Vec<(String, u32)>,
 
-    let result = parse_part_1(input.as_str()).expect("Must be able to parse input");
+    /// Also recognise decimal digits from other scripts, such as Arabic-Indic or full-width digits
+    #[arg(long)]
+    unicode_digits: bool,
 
-    println!("Part 1: {}", result);
+    /// Memory-map the input and sum chunks of it on several threads
+    #[arg(long)]
+    parallel: bool,
 
-    let result = parse_part_2(input.as_str()).expect("Must be able to parse input");
+    /// How part 2 reads words sharing letters, such as 'eightwo'
+    #[arg(long, value_enum, default_value_t = Strategy::Overlapping)]
+    overlap: Strategy,
 
-    println!("Part 2: {}", result);
+    /// Print the words picked from each line instead of solving, for part 2 unless '--part 1'
+    #[arg(long, value_enum, num_args = 0..=1, require_equals = true, default_missing_value = "auto")]
+    explain: Option<Style>,
 }
 
-fn parse_part_1(input: &str) -> Option<u128> {
-    let mut result = 0;
-
-    for line in input.lines() {
-        let (first, second) = first_and_last_digit(line)?;
-        result += (10 * first + second) as u128;
-    }
-
-    Some(result)
-}
-
-fn first_and_last_digit(input: &str) -> Option<(u32, u32)> {
-    let mut first = None;
-    let mut last = None;
-
-    for character in input.chars() {
-        let Some(digit) = character.to_digit(10) else {
-            continue;
-        };
-
-        if first.is_none() {
-            first = Some(digit);
-        }
-
-        last = Some(digit);
-    }
-
-    match (first, last) {
-        (None, None) => None,
-        (None, Some(_)) => unreachable!(),
-        (Some(value), None) => Some((value, value)),
-        (Some(first), Some(last)) => Some((first, last)),
-    }
+/// How '--explain' marks the words picked from each line
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Style {
+    /// Colours when printing to a terminal, brackets otherwise
+    Auto,
+    /// The first word in '[]' and the last in '{}'
+    Brackets,
+    /// The first word in green and the last in blue
+    Ansi,
 }
 
-fn parse_part_2(input: &str) -> Option<u128> {
-    let mut result = 0;
-
-    for line in input.lines() {
-        let search = [
-            ("0", 0),
-            ("1", 1),
-            ("2", 2),
-            ("3", 3),
-            ("4", 4),
-            ("5", 5),
-            ("6", 6),
-            ("7", 7),
-            ("8", 8),
-            ("9", 9),
-            ("zero", 0),
-            ("one", 1),
-            ("two", 2),
-            ("three", 3),
-            ("four", 4),
-            ("five", 5),
-            ("six", 6),
-            ("seven", 7),
-            ("eight", 8),
-            ("nine", 9),
-        ];
-
-        let mut first: Option<(usize, u8)> = None;
-        let mut last: Option<(usize, u8)> = None;
-
-        for &(pattern, value) in search.iter() {
-            first = match (first, line.find(pattern)) {
-                (None, None) => None,
-                (None, Some(position)) => Some((position, value)),
-                (Some((old_position, _)), Some(new_position)) if new_position < old_position => {
-                    Some((new_position, value))
-                }
-                (existing, _) => existing,
-            };
-
-            last = match (last, line.rfind(pattern)) {
-                (None, None) => None,
-                (None, Some(position)) => Some((position, value)),
-                (Some((old_position, _)), Some(new_position)) if new_position > old_position => {
-                    Some((new_position, value))
-                }
-                (existing, _) => existing,
-            };
-        }
-
-        let value = match (first, last) {
-            (None, None) => panic!("Could not find digit in string"),
-            (None, Some(_)) => unreachable!(),
-            (Some((_, value)), None) => 11 * value,
-            (Some((_, first)), Some((_, last))) => 10 * first + last,
-        };
-
-        result += value as u128;
-    }
-
-    Some(result)
+fn parse_word(s: &str) -> Result<(String, u32), String> {
+    day_1::parse_entry(s).map_err(common::settings::describe)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example_1() {
-        let input = r#"1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet"#;
-
-        assert_eq!(parse_part_1(input), Some(142));
-    }
-
-    #[test]
-    fn example_2() {
-        let input = r#"two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen"#;
-
-        assert_eq!(parse_part_2(input), Some(281));
-    }
+fn main() -> ExitCode {
+    let mut args = Args::parse();
 
-    #[test]
-    fn example_2_1() {
-        let input = r#"two1nine"#;
+    let mut puzzle = Puzzle::default();
 
-        assert_eq!(parse_part_2(input), Some(29));
+    if let Some(path) = &args.lexicon {
+        match common::settings::load::<Lexicon>(path) {
+            Ok(lexicon) => puzzle.words = lexicon,
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
+        }
     }
 
-    #[test]
-    fn example_2_2() {
-        let input = r#"eightwothree"#;
-
-        assert_eq!(parse_part_2(input), Some(83));
+    for (word, value) in args.words {
+        puzzle.words.insert(word, value);
     }
 
-    #[test]
-    fn example_2_3() {
-        let input = r#"abcone2threexyz"#;
+    puzzle.strategy = args.overlap;
+    puzzle.parallel = args.parallel;
+    args.common.mmap |= args.parallel;
 
-        assert_eq!(parse_part_2(input), Some(13));
+    if args.unicode_digits {
+        puzzle.digits.extend(&Lexicon::unicode_digits());
     }
 
-    #[test]
-    fn example_2_4() {
-        let input = r#"xtwone3four"#;
+    let part = args.common.part.unwrap_or(Part::Two);
 
-        assert_eq!(parse_part_2(input), Some(24));
-    }
-
-    #[test]
-    fn example_2_5() {
-        let input = r#"4nineeightseven2"#;
+    let mut runner = match Runner::new(args.common) {
+        Ok(runner) => runner,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
 
-        assert_eq!(parse_part_2(input), Some(42));
-    }
+    if let Some(style) = args.explain {
+        let highlight = match style {
+            Style::Auto if std::io::stdout().is_terminal() => Highlight::Ansi,
+            Style::Auto | Style::Brackets => Highlight::Brackets,
+            Style::Ansi => Highlight::Ansi,
+        };
 
-    #[test]
-    fn example_2_6() {
-        let input = r#"zoneight234"#;
+        let reader = puzzle.reader(part);
 
-        assert_eq!(parse_part_2(input), Some(14));
+        runner.each_input(Puzzle::DAY, |path, input| {
+            println!("{}:", path);
+            print!("{}", day_1::explain(&reader, input, highlight));
+            Ok(())
+        });
+    } else {
+        runner.run_with(&puzzle);
     }
 
-    #[test]
-    fn example_2_7() {
-        let input = r#"7pqrstsixteen"#;
-
-        assert_eq!(parse_part_2(input), Some(76));
-    }
+    runner.finish()
 }
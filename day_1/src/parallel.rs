@@ -0,0 +1,90 @@
+use common::Error;
+use rayon::prelude::*;
+
+use crate::{calibration_sum, Reader};
+
+/// Smallest chunk worth handing to another thread.
+pub const CHUNK_SIZE: usize = 1 << 16;
+
+/// Split the input into chunks of about `size` bytes, each ending after a newline.
+pub fn chunks(input: &str, size: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = input;
+
+    while rest.len() > size {
+        let Some(end) = rest.as_bytes()[size..]
+            .iter()
+            .position(|&byte| byte == b'\n')
+        else {
+            break;
+        };
+
+        let (chunk, tail) = rest.split_at(size + end + 1);
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+
+    chunks
+}
+
+/// [`calibration_sum`] over chunks of the input in parallel.
+///
+/// Failures are reported for the earliest line, as when summing sequentially.
+pub fn calibration_sum_parallel(reader: &Reader, input: &str, size: usize) -> Result<u128, Error> {
+    chunks(input, size)
+        .par_iter()
+        .map(|chunk| calibration_sum(reader, chunk))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Lexicon, Strategy};
+
+    #[test]
+    fn newline_aligned_chunks() {
+        const INPUT: &str = "ab\ncdef\ng\n\nhij";
+
+        assert_eq!(chunks(INPUT, 1), ["ab\n", "cdef\n", "g\n", "\nhij"]);
+        assert_eq!(chunks(INPUT, 4), ["ab\ncdef\n", "g\n\nhij"]);
+        assert_eq!(chunks(INPUT, 100), [INPUT]);
+        assert!(chunks("", 1).is_empty());
+    }
+
+    #[test]
+    fn matches_sequential() {
+        const INPUT: &str = r#"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+"#;
+
+        let reader = Reader::new(
+            &Lexicon::digits(),
+            &Lexicon::english(),
+            Strategy::Overlapping,
+        );
+
+        for size in [1, 5, 20, 1000] {
+            assert_eq!(calibration_sum_parallel(&reader, INPUT, size), Ok(281));
+        }
+
+        const MISSING: &str = "1abc2\npqrstuvwx\nabc\n7";
+
+        let error = calibration_sum_parallel(&reader, MISSING, 1)
+            .unwrap_err()
+            .locate(MISSING);
+
+        assert_eq!(error.location(), Some((2, 1)));
+    }
+}
@@ -0,0 +1,169 @@
+use aho_corasick::{AhoCorasick, AhoCorasickKind, MatchKind};
+
+/// Finds the first and last digit in a line with a single pass from each end.
+///
+/// Both passes run independently, so overlapping words are still found: in
+/// "eightwo" the first digit is 8 and the last is 2. The last digit is the one
+/// whose pattern ends furthest right.
+///
+/// Where several words start (or, for the last digit, end) at the same place,
+/// the longest wins, so "sixteen" is never read as "six".
+pub struct Scanner {
+    forward: AhoCorasick,
+    backward: AhoCorasick,
+    values: Vec<u32>,
+}
+
+impl Scanner {
+    /// Create a scanner for the provided patterns and the numbers they stand for.
+    pub fn new<'a>(patterns: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        let patterns = patterns.into_iter().collect::<Vec<_>>();
+
+        let build = |patterns: Vec<Vec<u8>>| {
+            AhoCorasick::builder()
+                .kind(Some(AhoCorasickKind::DFA))
+                .match_kind(MatchKind::LeftmostLongest)
+                .build(patterns)
+                .expect("Digit patterns must build an automaton")
+        };
+
+        let forward = build(
+            patterns
+                .iter()
+                .map(|(pattern, _)| pattern.as_bytes().to_vec())
+                .collect(),
+        );
+
+        let backward = build(
+            patterns
+                .iter()
+                .map(|(pattern, _)| pattern.bytes().rev().collect())
+                .collect(),
+        );
+
+        Self {
+            forward,
+            backward,
+            values: patterns.iter().map(|&(_, value)| value).collect(),
+        }
+    }
+
+    /// First and last digit in the line.
+    ///
+    /// `buffer` holds the reversed line for the backward pass, and can be reused
+    /// between calls to avoid allocating.
+    pub fn first_and_last(&self, line: &str, buffer: &mut Vec<u8>) -> Option<(u8, u8)> {
+        let (first, last) = self.find(line, buffer)?;
+
+        Some((first.first_digit(), last.last_digit()))
+    }
+
+    /// First and last matches in the line, with their positions.
+    ///
+    /// Both matches are the same when the line contains a single word.
+    pub fn find(&self, line: &str, buffer: &mut Vec<u8>) -> Option<(Found, Found)> {
+        let first = self.forward.find(line)?;
+
+        buffer.clear();
+        buffer.extend(line.bytes().rev());
+
+        let last = self.backward.find(buffer.as_slice())?;
+
+        let first = Found {
+            start: first.start(),
+            end: first.end(),
+            value: self.values[first.pattern().as_usize()],
+        };
+
+        let last = Found {
+            start: line.len() - last.end(),
+            end: line.len() - last.start(),
+            value: self.values[last.pattern().as_usize()],
+        };
+
+        Some((first, last))
+    }
+
+    /// First and last of the words read left to right without sharing letters.
+    ///
+    /// In "eightwo" only "eight" is found, as "two" starts inside it.
+    pub fn find_non_overlapping(&self, line: &str) -> Option<(Found, Found)> {
+        let mut matches = self.forward.find_iter(line).map(|found| Found {
+            start: found.start(),
+            end: found.end(),
+            value: self.values[found.pattern().as_usize()],
+        });
+
+        let first = matches.next()?;
+        let last = matches.last().unwrap_or(first);
+
+        Some((first, last))
+    }
+}
+
+/// A word found by a [`Scanner`], as a byte range of its line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Found {
+    pub start: usize,
+    pub end: usize,
+    /// Number the word stands for.
+    pub value: u32,
+}
+
+impl Found {
+    /// Digit contributed when this is the first word in a line.
+    pub fn first_digit(&self) -> u8 {
+        let mut value = self.value;
+
+        while value >= 10 {
+            value /= 10;
+        }
+
+        value as u8
+    }
+
+    /// Digit contributed when this is the last word in a line.
+    pub fn last_digit(&self) -> u8 {
+        (self.value % 10) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_words() {
+        let scanner = Scanner::new([("2", 2), ("4", 4), ("one", 1), ("two", 2), ("eight", 8)]);
+        let mut buffer = Vec::new();
+
+        assert_eq!(scanner.first_and_last("eightwo", &mut buffer), Some((8, 2)));
+        assert_eq!(
+            scanner.first_and_last("zoneight234", &mut buffer),
+            Some((1, 4))
+        );
+        assert_eq!(scanner.first_and_last("oneight", &mut buffer), Some((1, 8)));
+        assert_eq!(scanner.first_and_last("xtwox", &mut buffer), Some((2, 2)));
+        assert_eq!(scanner.first_and_last("abc", &mut buffer), None);
+    }
+
+    #[test]
+    fn found_positions() {
+        let scanner = Scanner::new([("2", 2), ("one", 1), ("two", 2), ("eight", 8)]);
+        let mut buffer = Vec::new();
+
+        let (first, last) = scanner
+            .find("xeightwo", &mut buffer)
+            .expect("Must find a digit");
+
+        assert_eq!((first.start, first.end, first.value), (1, 6, 8));
+        assert_eq!((last.start, last.end, last.value), (5, 8, 2));
+
+        let (first, last) = scanner
+            .find("ab2c", &mut buffer)
+            .expect("Must find a digit");
+
+        assert_eq!(first, last);
+        assert_eq!((first.start, first.end), (2, 3));
+    }
+}
@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+
+use crate::{Found, Lexicon, Scanner};
+
+/// How words sharing letters, such as "eightwo", are read.
+#[derive(clap::ValueEnum, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Every word counts, even inside another: "eightwo" reads as 8 and 2.
+    #[default]
+    Overlapping,
+    /// Words are read left to right and cannot share letters: "eightwo" reads as 8 only.
+    GreedyNonOverlapping,
+    /// Each word is replaced by its number in lexicon order before looking for
+    /// digits: "eightwo" becomes "eigh2".
+    ReplaceThenScan,
+}
+
+/// Finds the first and last words in a line following a [`Strategy`].
+pub struct Reader {
+    scanner: Scanner,
+    strategy: Strategy,
+    replacements: Vec<(String, String)>,
+}
+
+impl Reader {
+    /// Create a reader recognising the words of both lexicons.
+    pub fn new(digits: &Lexicon, words: &Lexicon, strategy: Strategy) -> Self {
+        match strategy {
+            Strategy::ReplaceThenScan => Self {
+                scanner: digits.scanner(),
+                strategy,
+                replacements: words
+                    .entries()
+                    .map(|(word, value)| (word.to_string(), value.to_string()))
+                    .collect(),
+            },
+            _ => {
+                let mut lexicon = digits.clone();
+                lexicon.extend(words);
+
+                Self {
+                    scanner: lexicon.scanner(),
+                    strategy,
+                    replacements: Vec::new(),
+                }
+            }
+        }
+    }
+
+    /// The text words are searched for in, which differs from the line only
+    /// when replacing words.
+    pub fn rewrite<'a>(&self, line: &'a str) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(line);
+
+        for (word, value) in &self.replacements {
+            if text.contains(word.as_str()) {
+                text = Cow::Owned(text.replace(word.as_str(), value));
+            }
+        }
+
+        text
+    }
+
+    /// First and last words in text returned by [`Reader::rewrite`].
+    pub fn find(&self, text: &str, buffer: &mut Vec<u8>) -> Option<(Found, Found)> {
+        match self.strategy {
+            Strategy::GreedyNonOverlapping => self.scanner.find_non_overlapping(text),
+            _ => self.scanner.find(text, buffer),
+        }
+    }
+
+    /// First and last digit in the line.
+    pub fn first_and_last(&self, line: &str, buffer: &mut Vec<u8>) -> Option<(u8, u8)> {
+        let (first, last) = self.find(&self.rewrite(line), buffer)?;
+
+        Some((first.first_digit(), last.last_digit()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(strategy: Strategy, line: &str) -> Option<(u8, u8)> {
+        Reader::new(&Lexicon::digits(), &Lexicon::english(), strategy)
+            .first_and_last(line, &mut Vec::new())
+    }
+
+    #[test]
+    fn strategies() {
+        assert_eq!(read(Strategy::Overlapping, "eightwothree"), Some((8, 3)));
+        assert_eq!(read(Strategy::Overlapping, "twone"), Some((2, 1)));
+
+        assert_eq!(
+            read(Strategy::GreedyNonOverlapping, "eightwothree"),
+            Some((8, 3))
+        );
+        assert_eq!(read(Strategy::GreedyNonOverlapping, "twone"), Some((2, 2)));
+        assert_eq!(
+            read(Strategy::GreedyNonOverlapping, "eightwo"),
+            Some((8, 8))
+        );
+
+        assert_eq!(
+            read(Strategy::ReplaceThenScan, "eightwothree"),
+            Some((2, 3))
+        );
+        assert_eq!(read(Strategy::ReplaceThenScan, "twone"), Some((1, 1)));
+        assert_eq!(read(Strategy::ReplaceThenScan, "abc"), None);
+    }
+
+    #[test]
+    fn rewrite() {
+        let reader = Reader::new(
+            &Lexicon::digits(),
+            &Lexicon::english(),
+            Strategy::ReplaceThenScan,
+        );
+
+        assert_eq!(reader.rewrite("eightwothree"), "eigh23");
+        assert!(matches!(reader.rewrite("abc"), Cow::Borrowed("abc")));
+    }
+}
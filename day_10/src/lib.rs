@@ -0,0 +1,337 @@
+use std::str::FromStr;
+
+use common::{profile::Counter, Direction, Error, Grid, Part, Position, Solution};
+
+static FLOOD_FILL_STEPS: Counter = Counter::new("flood-fill steps");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    VerticalPipe,
+    HorizontalPipe,
+    BendNorthEast,
+    BendNorthWest,
+    BendSouthWest,
+    BendSouthEast,
+    Ground,
+    Start,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Self::VerticalPipe),
+            '-' => Ok(Self::HorizontalPipe),
+            'L' => Ok(Self::BendNorthEast),
+            'J' => Ok(Self::BendNorthWest),
+            '7' => Ok(Self::BendSouthWest),
+            'F' => Ok(Self::BendSouthEast),
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::Start),
+            _ => Err("Unknown Tile"),
+        }
+    }
+}
+
+impl Tile {
+    /// Directions this tile connects to, if it is a pipe.
+    fn connections(self) -> Option<[Direction; 2]> {
+        match self {
+            Tile::VerticalPipe => Some([Direction::South, Direction::North]),
+            Tile::HorizontalPipe => Some([Direction::East, Direction::West]),
+            Tile::BendNorthEast => Some([Direction::North, Direction::East]),
+            Tile::BendNorthWest => Some([Direction::North, Direction::West]),
+            Tile::BendSouthWest => Some([Direction::South, Direction::West]),
+            Tile::BendSouthEast => Some([Direction::South, Direction::East]),
+            Tile::Ground | Tile::Start => None,
+        }
+    }
+}
+
+pub struct Map {
+    tiles: Grid<Tile>,
+    start: Position,
+}
+
+impl Map {
+    fn try_replace_start(mut self) -> Result<Self, &'static str> {
+        let [north, east, south, west] = Direction::ALL.map(|direction| {
+            self.tiles
+                .step(self.start, direction)
+                .and_then(|position| self.tiles[position].connections())
+                .is_some_and(|connections| connections.contains(&direction.opposite()))
+        });
+
+        let start_tile = match (north, south, west, east) {
+            (true, true, false, false) => Ok(Tile::VerticalPipe),
+            (false, false, true, true) => Ok(Tile::HorizontalPipe),
+            (true, false, true, false) => Ok(Tile::BendNorthWest),
+            (true, false, false, true) => Ok(Tile::BendNorthEast),
+            (false, true, true, false) => Ok(Tile::BendSouthWest),
+            (false, true, false, true) => Ok(Tile::BendSouthEast),
+            _ => Err("Starting tile has ambiguous connections"),
+        }?;
+
+        self.tiles[self.start] = start_tile;
+
+        Ok(self)
+    }
+
+    fn path(&self) -> Result<Vec<Position>, &'static str> {
+        let mut path = Vec::new();
+
+        path.push(self.start);
+
+        while path.len() == 1 || path.last() != Some(&self.start) {
+            let position = *path.last().unwrap();
+            let last = path.iter().nth_back(1).copied();
+
+            let [option_1, option_2] = self.tiles[position]
+                .connections()
+                .ok_or("Landed on Invalid Tile")?
+                .map(|direction| self.tiles.step(position, direction));
+
+            let next = if option_1 == last { option_2 } else { option_1 };
+
+            path.push(next.ok_or("Path leaves the map")?);
+        }
+
+        Ok(path)
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = s.parse::<Grid<Tile>>()?;
+
+        let start = tiles
+            .iter()
+            .find(|(_, &tile)| tile == Tile::Start)
+            .map(|(position, _)| position)
+            .ok_or_else(|| Error::end_of("Could not find starting position", s))?;
+
+        Ok(Self { tiles, start })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FillState {
+    Unknown,
+    Left,
+    Right,
+    Path,
+}
+
+fn enclosed_tiles(map: &Map) -> Option<usize> {
+    let path = map.path().ok()?;
+
+    let mut tiles = map.tiles.map(|_| FillState::Unknown);
+
+    for &position in path.iter() {
+        tiles[position] = FillState::Path;
+    }
+
+    for window in path.windows(2) {
+        let &[last, this] = window else {
+            unreachable!()
+        };
+
+        let Some(direction) = Direction::ALL
+            .into_iter()
+            .find(|&direction| map.tiles.step(last, direction) == Some(this))
+        else {
+            continue;
+        };
+
+        for position in [last, this] {
+            for (side, fill) in [
+                (direction.left(), FillState::Left),
+                (direction.right(), FillState::Right),
+            ] {
+                if let Some(side) = tiles.step(position, side) {
+                    if tiles[side] == FillState::Unknown {
+                        tiles[side] = fill;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut frontier = tiles
+        .iter()
+        .filter(|(_, state)| matches!(state, FillState::Left | FillState::Right))
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+
+    while let Some(position) = frontier.pop() {
+        FLOOD_FILL_STEPS.increment();
+
+        let fill = tiles[position];
+
+        for neighbour in map.tiles.neighbours_4(position) {
+            if tiles[neighbour] == FillState::Unknown {
+                tiles[neighbour] = fill;
+                frontier.push(neighbour);
+            }
+        }
+    }
+
+    // Any tile on the border of the map which isn't part of the path must be outside of it.
+    let (_, excluded) = tiles
+        .iter()
+        .filter(|&(position, _)| tiles.is_edge(position))
+        .find(|(_, state)| matches!(state, FillState::Left | FillState::Right))?;
+
+    let result = match excluded {
+        FillState::Left => Some(
+            tiles
+                .iter()
+                .filter(|(_, &state)| state == FillState::Right)
+                .count(),
+        ),
+        FillState::Right => Some(
+            tiles
+                .iter()
+                .filter(|(_, &state)| state == FillState::Left)
+                .count(),
+        ),
+        _ => None,
+    };
+
+    // let mut imgbuf = image::ImageBuffer::new(tiles.width() as u32, tiles.height() as u32);
+    //
+    // for (x, y, pixel) in imgbuf.enumerate_pixels_mut() {
+    //     let channels: [u8; 3] = match tiles.get((y as usize, x as usize)) {
+    //         Some(FillState::Path) => [0, 255, 0],
+    //         Some(FillState::Left) => [255, 0, 0],
+    //         Some(FillState::Right) => [0, 0, 255],
+    //         _ => [255, 255, 255],
+    //     };
+    //
+    //     *pixel = image::Rgb(channels);
+    // }
+    //
+    // imgbuf.save("map.png").unwrap();
+
+    result
+}
+
+#[derive(Default)]
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+    const EXAMPLE: &'static str = r#".....
+.S-7.
+.|.|.
+.L-J.
+....."#;
+
+    type Input<'a> = Map;
+    type Answer = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        Ok(input.parse::<Map>()?.try_replace_start()?)
+    }
+
+    fn part_1(&self, map: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        let cycle = map.path()?.len();
+
+        Ok(cycle / 2)
+    }
+
+    fn part_2(&self, map: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        enclosed_tiles(map).ok_or_else(|| "Could not determine enclosed tiles".into())
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Option<usize> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn solve_part_2(input: &str) -> Option<usize> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_example_1() {
+        const INPUT: &str = r#".....
+.S-7.
+.|.|.
+.L-J.
+....."#;
+
+        let map = INPUT
+            .parse::<Map>()
+            .expect("Must be able to parse map")
+            .try_replace_start()
+            .expect("Must be able to replace start");
+
+        assert_eq!(map.tiles[map.start], Tile::BendSouthEast);
+    }
+
+    #[test]
+    fn parse_example_2() {
+        const INPUT: &str = r#"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."#;
+
+        let map = INPUT
+            .parse::<Map>()
+            .expect("Must be able to parse map")
+            .try_replace_start()
+            .expect("Must be able to replace start");
+
+        assert_eq!(map.tiles[map.start], Tile::BendSouthEast);
+    }
+
+    #[test]
+    fn example_1_part_1() {
+        const INPUT: &str = r#".....
+.S-7.
+.|.|.
+.L-J.
+....."#;
+        const RESULT: Option<usize> = Some(4);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_2_part_1() {
+        const INPUT: &str = r#"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ..."#;
+        const RESULT: Option<usize> = Some(8);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_1_part_2() {
+        const INPUT: &str = r#"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."#;
+        const RESULT: Option<usize> = Some(4);
+
+        assert_eq!(solve_part_2(INPUT), RESULT);
+    }
+}
@@ -0,0 +1,267 @@
+use std::str::FromStr;
+
+use common::{Error, Grid, Part, Position, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Galaxy,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '#' => Ok(Tile::Galaxy),
+            _ => Err("Unknown Tile"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    tiles: Grid<Tile>,
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { tiles: s.parse()? })
+    }
+}
+
+/// Positions of every galaxy, after the universe has expanded.
+#[derive(Debug, Clone)]
+struct Galaxies {
+    positions: Vec<Position>,
+}
+
+impl Galaxies {
+    fn pairs(&self) -> impl Iterator<Item = (Position, Position)> + '_ {
+        self.positions
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &a)| self.positions.iter().skip(index + 1).map(move |&b| (a, b)))
+    }
+}
+
+impl Map {
+    #[allow(dead_code)]
+    fn save(&self, name: &str) {
+        let mut imgbuf =
+            image::ImageBuffer::new(self.tiles.width() as u32, self.tiles.height() as u32);
+
+        imgbuf.fill(0);
+
+        for ((y, x), &tile) in self.tiles.iter() {
+            if tile == Tile::Galaxy {
+                let pixel = imgbuf.get_pixel_mut(x as u32, y as u32);
+                *pixel = image::Rgb([255u8, 255u8, 255u8]);
+            }
+        }
+
+        imgbuf.save(name).unwrap();
+    }
+
+    /// Positions of every galaxy once each empty row and column has grown by `size`.
+    fn expand_by(&self, size: usize) -> Galaxies {
+        // Total expansion before each row and column
+        let expansion = |lines: Vec<bool>| {
+            lines
+                .into_iter()
+                .scan(0, |expansion, empty| {
+                    let before = *expansion;
+
+                    if empty {
+                        *expansion += size;
+                    }
+
+                    Some(before)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let rows = expansion(
+            self.tiles
+                .rows()
+                .map(|row| row.iter().all(|&tile| tile == Tile::Empty))
+                .collect(),
+        );
+
+        let columns = expansion(
+            self.tiles
+                .columns()
+                .map(|mut column| column.all(|&tile| tile == Tile::Empty))
+                .collect(),
+        );
+
+        let positions = self
+            .tiles
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Galaxy)
+            .map(|((y, x), _)| (y + rows[y], x + columns[x]))
+            .collect();
+
+        Galaxies { positions }
+    }
+}
+
+#[derive(Default)]
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+    const EXAMPLE: &'static str = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+
+    type Input<'a> = Map;
+    type Answer = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input.parse()
+    }
+
+    fn part_1(&self, map: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        let sum = map
+            .expand_by(1)
+            .pairs()
+            .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            .sum();
+
+        Ok(sum)
+    }
+
+    fn part_2(&self, map: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        let sum = map
+            .expand_by(1_000_000 - 1)
+            .pairs()
+            .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            .sum();
+
+        Ok(sum)
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Result<usize, &'static str> {
+    common::solve::<Puzzle>(input, Part::One).map_err(|error| error.message())
+}
+
+pub fn solve_part_2(input: &str) -> Result<usize, &'static str> {
+    common::solve::<Puzzle>(input, Part::Two).map_err(|error| error.message())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1_part_1() {
+        const INPUT: &str = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+        const RESULT: Result<usize, &'static str> = Ok(374);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_1_part_2() {
+        const INPUT: &str = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+        const RESULT: usize = 1030;
+
+        let sum: usize = INPUT
+            .parse::<Map>()
+            .expect("Must be able to parse")
+            .expand_by(9)
+            .pairs()
+            .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            .sum();
+
+        assert_eq!(sum, RESULT);
+    }
+
+    #[test]
+    fn example_2_part_2() {
+        const INPUT: &str = r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#;
+        const RESULT: usize = 8410;
+
+        let sum: usize = INPUT
+            .parse::<Map>()
+            .expect("Must be able to parse")
+            .expand_by(99)
+            .pairs()
+            .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            .sum();
+
+        assert_eq!(sum, RESULT);
+    }
+
+    #[test]
+    fn example_3_part_2() {
+        const INPUT: &str = r#"#.#"#;
+        const RESULT: usize = 2;
+
+        let sum: usize = INPUT
+            .parse::<Map>()
+            .expect("Must be able to parse")
+            .expand_by(0)
+            .pairs()
+            .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            .sum();
+
+        assert_eq!(sum, RESULT);
+    }
+
+    #[test]
+    fn example_4_part_2() {
+        const INPUT: &str = r#"#.#"#;
+        const RESULT: usize = 3;
+
+        let sum: usize = INPUT
+            .parse::<Map>()
+            .expect("Must be able to parse")
+            .expand_by(1)
+            .pairs()
+            .map(|(a, b)| a.0.abs_diff(b.0) + a.1.abs_diff(b.1))
+            .sum();
+
+        assert_eq!(sum, RESULT);
+    }
+}
@@ -0,0 +1,130 @@
+use std::{fmt::Display, str::FromStr};
+
+use common::{settings, Error};
+
+/// Cubes known to be in the bag, by colour.
+///
+/// Written as `colour=count` [settings](common::settings):
+///
+/// ```text
+/// red=12, green=13, blue=14
+/// yellow=3
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    counts: Vec<(String, u32)>,
+}
+
+impl Default for Bag {
+    /// The bag from the puzzle: 12 red, 13 green and 14 blue cubes.
+    fn default() -> Self {
+        Self {
+            counts: vec![
+                ("red".to_string(), 12),
+                ("green".to_string(), 13),
+                ("blue".to_string(), 14),
+            ],
+        }
+    }
+}
+
+impl Bag {
+    /// Cubes of this colour in the bag, which is zero for unknown colours.
+    pub fn count(&self, colour: &str) -> u32 {
+        self.counts
+            .iter()
+            .find(|(name, _)| name == colour)
+            .map_or(0, |&(_, count)| count)
+    }
+
+    pub fn counts(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+
+    /// Number of cubes in the bag.
+    pub fn total(&self) -> u128 {
+        self.counts.iter().map(|&(_, count)| count as u128).sum()
+    }
+
+    /// Product of the counts of each colour in the bag, ignoring empty colours,
+    /// or `None` if it overflows.
+    pub fn power(&self) -> Option<u128> {
+        self.counts
+            .iter()
+            .filter(|&&(_, count)| count > 0)
+            .try_fold(1u128, |power, &(_, count)| power.checked_mul(count as u128))
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Bag {
+    fn from_iter<T: IntoIterator<Item = (S, u32)>>(iter: T) -> Self {
+        Self {
+            counts: iter
+                .into_iter()
+                .map(|(colour, count)| (colour.into(), count))
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for Bag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut counts: Vec<(String, u32)> = Vec::new();
+
+        for setting in settings::settings(s, "Expected 'colour=count'") {
+            let (colour, count) = setting?;
+
+            if colour.is_empty() {
+                return Err(Error::end_of("Missing cube colour", colour));
+            }
+
+            let count = count
+                .parse()
+                .map_err(|_| Error::new("Could not parse cube count", count))?;
+
+            match counts.iter_mut().find(|(name, _)| name == colour) {
+                Some(existing) => existing.1 = count,
+                None => counts.push((colour.to_string(), count)),
+            }
+        }
+
+        Ok(Self { counts })
+    }
+}
+
+impl Display for Bag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let counts = self
+            .counts
+            .iter()
+            .map(|(colour, count)| format!("{}={}", colour, count))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", counts.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bag() {
+        const INPUT: &str = "# Puzzle bag\nred=12, green=13,blue = 14\nyellow=3\n";
+
+        let bag = INPUT.parse::<Bag>().expect("Must be able to parse bag");
+
+        assert_eq!(bag.to_string(), "red=12,green=13,blue=14,yellow=3");
+        assert_eq!(bag.count("yellow"), 3);
+        assert_eq!(bag.count("purple"), 0);
+
+        let error = "red=12,green".parse::<Bag>().unwrap_err();
+
+        assert_eq!(error.message(), "Expected 'colour=count'");
+        assert_eq!(error.snippet(), "green");
+    }
+}
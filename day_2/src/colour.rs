@@ -0,0 +1,72 @@
+use std::{fmt::Display, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+/// Name of a cube colour, shared between every round it appears in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Colour(Arc<str>);
+
+impl Colour {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Colour {
+    fn from(name: &str) -> Self {
+        Self(name.into())
+    }
+}
+
+impl Display for Colour {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for Colour {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Colour {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+/// Hands out a single [`Colour`] for each distinct name.
+#[derive(Debug, Default)]
+pub struct Interner {
+    colours: Vec<Colour>,
+}
+
+impl Interner {
+    pub fn intern(&mut self, name: &str) -> Colour {
+        if let Some(colour) = self.colours.iter().find(|colour| colour.as_str() == name) {
+            return colour.clone();
+        }
+
+        let colour = Colour::from(name);
+        self.colours.push(colour.clone());
+        colour
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interned_colours_are_shared() {
+        let mut interner = Interner::default();
+
+        let red = interner.intern("red");
+        let blue = interner.intern("blue");
+
+        assert!(Arc::ptr_eq(&red.0, &interner.intern("red").0));
+        assert_ne!(red, blue);
+        assert_eq!(red, Colour::from("red"));
+    }
+}
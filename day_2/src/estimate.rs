@@ -0,0 +1,406 @@
+use std::fmt::Display;
+
+use common::Error;
+
+use crate::{Colour, Dice};
+
+/// Drop in log-likelihood bounding a 95% profile-likelihood interval, half the
+/// 95% quantile of the chi-squared distribution with one degree of freedom.
+const INTERVAL_DROP: f64 = 1.920729;
+
+/// Most likely number of cubes of one colour in the bag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColourEstimate {
+    pub colour: Colour,
+    pub count: u32,
+    /// 95% confidence interval for the count.
+    pub interval: (u32, u32),
+}
+
+/// Most likely bag to have produced a set of rounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub colours: Vec<ColourEstimate>,
+    /// Natural log of the probability of the rounds given the estimated bag.
+    pub log_likelihood: f64,
+    /// Largest count of a colour considered.
+    pub max_cubes: u32,
+    /// Whether every colour's interval stops short of the largest count
+    /// considered. Otherwise bags with more cubes than considered may be as
+    /// likely, and since colours share the bag's total, no count is reliable.
+    pub bounded: bool,
+}
+
+impl Display for Estimate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Counts of estimates which larger bags than considered could change are marked with '+'
+        let bound = |count: u32| {
+            if self.bounded {
+                count.to_string()
+            } else {
+                format!("{}+", count)
+            }
+        };
+
+        for (index, estimate) in self.colours.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(
+                f,
+                "{} {} [{}, {}]",
+                bound(estimate.count),
+                estimate.colour,
+                estimate.interval.0,
+                bound(estimate.interval.1)
+            )?;
+        }
+
+        write!(f, " (log-likelihood {:.3})", self.log_likelihood)
+    }
+}
+
+/// Natural logs of the binomial coefficients up to a size.
+struct Binomials {
+    log_factorials: Vec<f64>,
+}
+
+impl Binomials {
+    fn new(size: usize) -> Self {
+        let log_factorials = (0..=size)
+            .scan(0.0, |sum, n| {
+                if n > 0 {
+                    *sum += (n as f64).ln();
+                }
+                Some(*sum)
+            })
+            .collect();
+
+        Self { log_factorials }
+    }
+
+    fn ln_choose(&self, n: u64, k: u64) -> f64 {
+        if k > n {
+            return f64::NEG_INFINITY;
+        }
+
+        let (n, k) = (n as usize, k as usize);
+        self.log_factorials[n] - self.log_factorials[k] - self.log_factorials[n - k]
+    }
+}
+
+/// Find the bag most likely to have produced the rounds, assuming each round
+/// draws its cubes without replacement from the same bag and returns them
+/// afterwards.
+///
+/// A round drawing `c` cubes of each colour, `n` in total, from a bag of `N`
+/// cubes has probability `prod(C(N_colour, c_colour)) / C(N, n)`, so the
+/// log-likelihood splits into a term per colour and a term for the bag's
+/// total. The best split of each total between colours is found by dynamic
+/// programming over the colours. Counts are capped at `max_cubes`, as drawing
+/// from a much larger bag can be more likely than any smaller bag, and rounds
+/// drawing more cubes of a colour than that are an error.
+///
+/// Returns `None` when no cubes were drawn.
+pub fn estimate<'a>(
+    rounds: impl IntoIterator<Item = &'a Dice>,
+    max_cubes: u32,
+) -> Result<Option<Estimate>, Error> {
+    let rounds = rounds.into_iter().collect::<Vec<_>>();
+
+    let mut colours: Vec<Colour> = Vec::new();
+
+    for round in &rounds {
+        for (colour, _) in &round.count {
+            if !colours.contains(colour) {
+                colours.push(colour.clone());
+            }
+        }
+    }
+
+    if colours.is_empty() {
+        return Ok(None);
+    }
+
+    let minimums = colours
+        .iter()
+        .map(|colour| {
+            rounds
+                .iter()
+                .map(|round| round.get(colour.as_str()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    if let Some((colour, _)) = colours
+        .iter()
+        .zip(&minimums)
+        .find(|&(_, &minimum)| minimum > max_cubes)
+    {
+        return Err(Error::new(
+            "Cubes drawn exceed the largest count considered",
+            colour.as_str(),
+        ));
+    }
+
+    let max_total = (max_cubes as usize)
+        .checked_mul(colours.len())
+        .ok_or_else(|| Error::from("Too many cubes to consider"))?;
+    let binomials = Binomials::new(max_total);
+
+    // Log-likelihood contributed by each colour, indexed by its count
+    let terms = colours
+        .iter()
+        .zip(&minimums)
+        .map(|(colour, &minimum)| {
+            (0..=max_cubes)
+                .map(|count| {
+                    if count < minimum {
+                        return f64::NEG_INFINITY;
+                    }
+
+                    rounds
+                        .iter()
+                        .map(|round| {
+                            binomials.ln_choose(count.into(), round.get(colour.as_str()).into())
+                        })
+                        .sum()
+                })
+                .collect::<Vec<f64>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Log-likelihood contributed by the size of each round, indexed by the bag total
+    let totals = (0..=max_total)
+        .map(|total| {
+            rounds
+                .iter()
+                .map(|round| -binomials.ln_choose(total as u64, round.total()))
+                .sum()
+        })
+        .collect::<Vec<f64>>();
+
+    let all = (0..colours.len()).collect::<Vec<_>>();
+    let (best, choices) = combine(&terms, &all, max_total);
+
+    let (total, log_likelihood) = (0..=max_total)
+        .map(|total| (total, best[total] + totals[total]))
+        .fold((0, f64::NEG_INFINITY), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        });
+
+    if log_likelihood == f64::NEG_INFINITY {
+        return Ok(None);
+    }
+
+    // Walk the choices back from the best total to find each colour's count
+    let mut counts = vec![0; colours.len()];
+    let mut remaining = total;
+
+    for colour in (0..colours.len()).rev() {
+        counts[colour] = choices[colour][remaining];
+        remaining -= counts[colour] as usize;
+    }
+
+    let colours = colours
+        .into_iter()
+        .enumerate()
+        .map(|(index, colour)| {
+            let others = all
+                .iter()
+                .copied()
+                .filter(|&other| other != index)
+                .collect::<Vec<_>>();
+
+            let (rest, _) = combine(&terms, &others, max_total);
+
+            // Best log-likelihood with this colour's count fixed
+            let profile = |count: u32| {
+                terms[index][count as usize]
+                    + (0..=max_total - count as usize)
+                        .map(|total| rest[total] + totals[total + count as usize])
+                        .fold(f64::NEG_INFINITY, f64::max)
+            };
+
+            let within = (0..=max_cubes)
+                .filter(|&count| profile(count) >= log_likelihood - INTERVAL_DROP)
+                .collect::<Vec<_>>();
+
+            let interval = (
+                within.first().copied().unwrap_or(counts[index]),
+                within.last().copied().unwrap_or(counts[index]),
+            );
+
+            ColourEstimate {
+                colour,
+                count: counts[index],
+                interval,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let bounded = colours
+        .iter()
+        .all(|estimate| estimate.interval.1 < max_cubes);
+
+    Ok(Some(Estimate {
+        colours,
+        log_likelihood,
+        max_cubes,
+        bounded,
+    }))
+}
+
+/// Best sum of the colours' terms for each bag total, and the count chosen for
+/// each colour given the total so far.
+fn combine(terms: &[Vec<f64>], colours: &[usize], max_total: usize) -> (Vec<f64>, Vec<Vec<u32>>) {
+    let mut best = vec![f64::NEG_INFINITY; max_total + 1];
+    best[0] = 0.0;
+
+    let mut choices = vec![vec![0; max_total + 1]; terms.len()];
+
+    for &colour in colours {
+        let mut next = vec![f64::NEG_INFINITY; max_total + 1];
+
+        for (total, &before) in best.iter().enumerate() {
+            if before == f64::NEG_INFINITY {
+                continue;
+            }
+
+            for (count, &term) in terms[colour].iter().enumerate() {
+                let value = before + term;
+
+                if total + count <= max_total && value > next[total + count] {
+                    next[total + count] = value;
+                    choices[colour][total + count] = count as u32;
+                }
+            }
+        }
+
+        best = next;
+    }
+
+    (best, choices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Game;
+
+    fn rounds(game: &str) -> Vec<Dice> {
+        game.parse::<Game>()
+            .expect("Must be able to parse game")
+            .rounds
+    }
+
+    /// Log-likelihood of the rounds for a bag, computed directly.
+    fn log_likelihood(rounds: &[Dice], bag: &[(&str, u32)]) -> f64 {
+        let binomials = Binomials::new(200);
+        let total = bag.iter().map(|&(_, count)| count).sum::<u32>();
+
+        rounds
+            .iter()
+            .map(|round| {
+                bag.iter()
+                    .map(|&(colour, count)| {
+                        binomials.ln_choose(count.into(), round.get(colour).into())
+                    })
+                    .sum::<f64>()
+                    - binomials.ln_choose(total.into(), round.total())
+            })
+            .sum()
+    }
+
+    #[test]
+    fn exhausted_bag() {
+        let rounds = rounds("Game 1: 2 red, 2 blue; 2 blue, 2 red; 2 red, 2 blue");
+
+        let estimate = estimate(&rounds, 30)
+            .expect("Counts must be within the cap")
+            .expect("Must estimate a bag");
+
+        assert_eq!(
+            estimate.to_string(),
+            "2 red [2, 3], 2 blue [2, 3] (log-likelihood 0.000)"
+        );
+    }
+
+    #[test]
+    fn capped_counts() {
+        let rounds = rounds("Game 1: 4000000000 red, 4000000000 blue");
+
+        let error = estimate(&rounds, 100).unwrap_err();
+
+        assert_eq!(error.snippet(), "red");
+
+        // A single draw of every cube is most likely from an unbounded bag
+        let rounds = self::rounds("Game 1: 1 red, 5 blue; 2 red, 3 blue");
+
+        let estimate = estimate(&rounds, 20)
+            .expect("Counts must be within the cap")
+            .expect("Must estimate a bag");
+
+        assert!(!estimate.bounded);
+        assert!(estimate.to_string().contains("+ "));
+    }
+
+    #[test]
+    fn capped_colour_unbounds_others() {
+        // Red alone stops well short of the cap, but blue reaches it
+        let rounds = rounds("Game 1: 5 blue; 5 blue; 5 blue; 1 red; 5 blue; 5 blue");
+
+        let estimate = estimate(&rounds, 20)
+            .expect("Counts must be within the cap")
+            .expect("Must estimate a bag");
+
+        let red = &estimate.colours[1];
+
+        assert!(red.interval.1 < 20);
+        assert!(!estimate.bounded);
+        assert!(estimate.to_string().contains("1+ red [1, 3+]"));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        const MAX_CUBES: u32 = 15;
+
+        let rounds =
+            rounds("Game 1: 3 red, 2 blue; 1 red, 4 blue, 1 green; 4 red, 3 blue, 1 green");
+
+        let estimate = estimate(&rounds, MAX_CUBES)
+            .expect("Counts must be within the cap")
+            .expect("Must estimate a bag");
+
+        let mut best = f64::NEG_INFINITY;
+
+        for red in 0..=MAX_CUBES {
+            for blue in 0..=MAX_CUBES {
+                for green in 0..=MAX_CUBES {
+                    let value =
+                        log_likelihood(&rounds, &[("red", red), ("blue", blue), ("green", green)]);
+                    best = best.max(value);
+                }
+            }
+        }
+
+        let bag = estimate
+            .colours
+            .iter()
+            .map(|colour| (colour.colour.as_str(), colour.count))
+            .collect::<Vec<_>>();
+
+        assert!((estimate.log_likelihood - best).abs() < 1e-9);
+        assert!((log_likelihood(&rounds, &bag) - best).abs() < 1e-9);
+
+        for colour in &estimate.colours {
+            assert!(colour.interval.0 <= colour.count && colour.count <= colour.interval.1);
+        }
+    }
+}
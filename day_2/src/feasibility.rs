@@ -0,0 +1,63 @@
+use std::fmt::Write;
+
+use crate::{Bag, Game};
+
+/// List whether each game was possible with the bag, and the first round and
+/// colour which ruled out those that were not.
+pub fn feasibility(games: &[Game], bag: &Bag) -> String {
+    let mut output = String::new();
+    let mut possible = 0;
+    let mut sum = 0u128;
+
+    let _ = writeln!(output, "Bag: {}", bag);
+
+    for game in games {
+        match game.first_excess(bag) {
+            Some(excess) => {
+                let _ = writeln!(output, "Game {}: impossible, {}", game.id, excess);
+            }
+            None => {
+                possible += 1;
+                sum += game.id as u128;
+                let _ = writeln!(output, "Game {}: possible", game.id);
+            }
+        }
+    }
+
+    let _ = writeln!(
+        output,
+        "{} of {} games possible, ID sum {}",
+        possible,
+        games.len(),
+        sum
+    );
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn example_report() {
+        const RESULT: &str = r#"Bag: red=12,green=13,blue=14
+Game 1: possible
+Game 2: possible
+Game 3: impossible, round 1 drew 20 red but the bag holds 12
+Game 4: impossible, round 3 drew 15 blue but the bag holds 14
+Game 5: possible
+3 of 5 games possible, ID sum 8
+"#;
+
+        let puzzle = Puzzle::default();
+        let games = puzzle
+            .parse(Puzzle::EXAMPLE)
+            .expect("Must be able to parse example");
+
+        assert_eq!(feasibility(&games, &puzzle.bag), RESULT);
+    }
+}
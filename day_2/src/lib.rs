@@ -0,0 +1,526 @@
+use std::{fmt::Display, str::FromStr};
+
+use common::{Error, Part, Solution};
+use serde::{Deserialize, Serialize};
+
+mod bag;
+mod colour;
+mod estimate;
+mod feasibility;
+mod search;
+mod stats;
+
+pub use bag::Bag;
+pub use colour::{Colour, Interner};
+pub use estimate::{estimate, ColourEstimate, Estimate};
+pub use feasibility::feasibility;
+pub use search::{optimal_bag, Objective, Optimum};
+pub use stats::{render, statistics, Summary};
+
+/// Cubes drawn in a round, by colour in the order they were listed.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Dice {
+    count: Vec<(Colour, u32)>,
+}
+
+impl Dice {
+    /// Product of the counts, or `None` if it overflows.
+    fn power(&self) -> Option<u128> {
+        self.count
+            .iter()
+            .try_fold(1u128, |power, &(_, count)| power.checked_mul(count as u128))
+    }
+
+    /// Number of cubes drawn.
+    fn total(&self) -> u64 {
+        self.count.iter().map(|&(_, count)| count as u64).sum()
+    }
+
+    /// Cubes of this colour, which is zero for colours not drawn.
+    fn get(&self, colour: &str) -> u32 {
+        self.count
+            .iter()
+            .find(|(name, _)| name.as_str() == colour)
+            .map_or(0, |&(_, count)| count)
+    }
+
+    /// Set the count for a colour, replacing any earlier count.
+    fn insert(&mut self, colour: Colour, count: u32) {
+        match self.count.iter_mut().find(|(name, _)| *name == colour) {
+            Some(existing) => existing.1 = count,
+            None => self.count.push((colour, count)),
+        }
+    }
+
+    /// First colour with more cubes than the bag holds.
+    fn excess(&self, bag: &Bag) -> Option<(&Colour, u32)> {
+        self.count
+            .iter()
+            .find(|&(colour, count)| *count > bag.count(colour.as_str()))
+            .map(|(colour, count)| (colour, *count))
+    }
+
+    fn parse(summary: &str, interner: &mut Interner) -> Result<Self, Error> {
+        let mut round = Self::default();
+
+        for cubes in summary.split(',') {
+            let mut split = cubes.trim().split(' ');
+
+            let count = split
+                .next()
+                .ok_or_else(|| Error::end_of("Missing cube count", cubes))?;
+
+            let count = count
+                .parse::<u32>()
+                .map_err(|_| Error::new("Could not parse cube count", count))?;
+
+            let colour = split
+                .next()
+                .ok_or_else(|| Error::end_of("Missing cube colour", cubes))?;
+
+            round.insert(interner.intern(colour), count);
+
+            if let Some(token) = split.next() {
+                return Err(Error::new("Unexpected token", token));
+            }
+        }
+
+        Ok(round)
+    }
+}
+
+impl Display for Dice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, (colour, count)) in self.count.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{} {}", count, colour)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Dice>,
+}
+
+impl Game {
+    /// Parse a game, sharing colour names with those already in the interner.
+    pub fn parse(value: &str, interner: &mut Interner) -> Result<Self, Error> {
+        let mut split = value
+            .strip_prefix("Game")
+            .ok_or_else(|| Error::new("Missing 'Game' token", value))?
+            .trim()
+            .split(':');
+
+        let id = split
+            .next()
+            .ok_or_else(|| Error::end_of("Missing ID", value))?
+            .trim();
+
+        let id = id
+            .parse::<u32>()
+            .map_err(|_| Error::new("Could not parse ID", id))?;
+
+        let rounds = split
+            .next()
+            .ok_or_else(|| Error::end_of("Missing ':' token", value))?
+            .split(';')
+            .map(str::trim)
+            .map(|round| Dice::parse(round, interner))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(token) = split.next() {
+            return Err(Error::new("Unexpected ':' token", token));
+        }
+
+        Ok(Self { id, rounds })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Cubes drawn in each round.
+    pub fn rounds(&self) -> &[Dice] {
+        &self.rounds
+    }
+
+    fn minimum_bag(&self) -> Dice {
+        self.rounds.iter().fold(Dice::default(), |mut bag, round| {
+            for (colour, count) in round.count.iter() {
+                match bag.count.iter_mut().find(|(name, _)| name == colour) {
+                    Some(existing) => existing.1 = existing.1.max(*count),
+                    None => bag.count.push((colour.clone(), *count)),
+                }
+            }
+
+            bag
+        })
+    }
+
+    /// First round which drew more cubes of a colour than the bag holds.
+    pub fn first_excess(&self, bag: &Bag) -> Option<Excess<'_>> {
+        self.rounds.iter().enumerate().find_map(|(index, round)| {
+            let (colour, count) = round.excess(bag)?;
+
+            Some(Excess {
+                round: index + 1,
+                colour: colour.as_str(),
+                count,
+                available: bag.count(colour.as_str()),
+            })
+        })
+    }
+
+    /// Check a game read from JSON can be written as text which parses back
+    /// into the same game.
+    fn validate(&self) -> Result<(), Error> {
+        if self.rounds.is_empty() {
+            return Err(Error::new(
+                "Game has no rounds",
+                &format!("Game {}", self.id),
+            ));
+        }
+
+        for round in &self.rounds {
+            if round.count.is_empty() {
+                return Err(Error::new(
+                    "Round draws no cubes",
+                    &format!("Game {}", self.id),
+                ));
+            }
+
+            for (index, (colour, _)) in round.count.iter().enumerate() {
+                let colour = colour.as_str();
+
+                if colour.is_empty()
+                    || colour
+                        .chars()
+                        .any(|char| char.is_whitespace() || matches!(char, ',' | ';' | ':'))
+                {
+                    return Err(Error::new("Invalid colour name", colour));
+                }
+
+                if round.count[..index]
+                    .iter()
+                    .any(|(other, _)| other.as_str() == colour)
+                {
+                    return Err(Error::new("Colour drawn twice in a round", colour));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Share colour names with those already in the interner.
+    fn intern(&mut self, interner: &mut Interner) {
+        for round in &mut self.rounds {
+            for (colour, _) in &mut round.count {
+                *colour = interner.intern(colour.as_str());
+            }
+        }
+    }
+}
+
+impl FromStr for Game {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, &mut Interner::default())
+    }
+}
+
+impl Display for Game {
+    /// Canonical "Game N: ..." text, which parses back into the same game.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+
+        for (index, round) in self.rounds.iter().enumerate() {
+            write!(f, "{} {}", if index > 0 { ";" } else { "" }, round)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse games from their canonical text, one per line.
+pub fn parse_games(input: &str) -> Result<Vec<Game>, Error> {
+    let mut interner = Interner::default();
+
+    input
+        .lines()
+        .map(|line| Game::parse(line, &mut interner))
+        .collect()
+}
+
+/// Parse games exported as JSON, rejecting games with no rounds, rounds with
+/// no cubes, and colour names which could not be read back from text.
+pub fn from_json(input: &str) -> Result<Vec<Game>, Error> {
+    let mut games = serde_json::from_str::<Vec<Game>>(input).map_err(|error| {
+        let line_start = input
+            .split_inclusive('\n')
+            .take(error.line().saturating_sub(1))
+            .map(str::len)
+            .sum::<usize>();
+
+        let mut offset = (line_start + error.column().saturating_sub(1)).min(input.len());
+
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        Error::new("Could not parse JSON games", &input[offset..])
+    })?;
+
+    let mut interner = Interner::default();
+
+    for game in &mut games {
+        game.validate()?;
+        game.intern(&mut interner);
+    }
+
+    Ok(games)
+}
+
+/// Export games as JSON.
+pub fn to_json(games: &[Game]) -> String {
+    serde_json::to_string_pretty(games).expect("Games must serialise as JSON")
+}
+
+/// Cubes drawn in a round beyond those in the bag, making a game impossible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Excess<'a> {
+    /// Round number, counting from 1.
+    pub round: usize,
+    pub colour: &'a str,
+    pub count: u32,
+    /// Cubes of this colour in the bag.
+    pub available: u32,
+}
+
+impl Display for Excess<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "round {} drew {} {} but the bag holds {}",
+            self.round, self.count, self.colour, self.available
+        )
+    }
+}
+
+/// Cube game scorer, checking games against its bag.
+#[derive(Default, Debug, Clone)]
+pub struct Puzzle {
+    /// Bag used by part 1.
+    pub bag: Bag,
+}
+
+impl Solution for Puzzle {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+    const EXAMPLE: &'static str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+
+    type Input<'a> = Vec<Game>;
+    type Answer = u128;
+
+    /// Games are read from their text, or from JSON when the input starts with '['.
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        if input.trim_start().starts_with('[') {
+            from_json(input)
+        } else {
+            parse_games(input)
+        }
+    }
+
+    fn part_1(&self, games: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        Ok(games
+            .iter()
+            .filter(|game| game.first_excess(&self.bag).is_none())
+            .map(|game| game.id as u128)
+            .sum())
+    }
+
+    fn part_2(&self, games: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        games
+            .iter()
+            .try_fold(0u128, |sum, game| {
+                game.minimum_bag()
+                    .power()
+                    .and_then(|power| sum.checked_add(power))
+            })
+            .ok_or_else(|| Error::from("Powers overflowed"))
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn solve_part_2(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1() {
+        const INPUT: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+        const RESULT: Option<u128> = Some(8);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_part_2() {
+        const INPUT: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
+        const RESULT: Option<u128> = Some(2286);
+
+        assert_eq!(solve_part_2(INPUT), RESULT);
+    }
+
+    #[test]
+    fn custom_bag() {
+        const INPUT: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 yellow
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"#;
+
+        let puzzle = Puzzle {
+            bag: "red=20,green=13,blue=14,yellow=1"
+                .parse()
+                .expect("Must be able to parse bag"),
+        };
+
+        assert_eq!(common::solve_with(&puzzle, INPUT, Part::One), Ok(1 + 3));
+
+        let games = puzzle.parse(INPUT).expect("Must be able to parse games");
+
+        assert_eq!(
+            games[1].first_excess(&puzzle.bag),
+            Some(Excess {
+                round: 1,
+                colour: "yellow",
+                count: 2,
+                available: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn display_round_trip() {
+        const INPUT: &str =
+            "Game 3:  8 green, 6 blue, 20 red;5 blue, 4 red, 13 green; 5 green, 1 red";
+        const RESULT: &str =
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+
+        let game = INPUT.parse::<Game>().expect("Must be able to parse game");
+
+        assert_eq!(game.to_string(), RESULT);
+        assert_eq!(RESULT.parse::<Game>(), Ok(game));
+
+        let large = "Game 4000000000: 300 red, 70000 blue"
+            .parse::<Game>()
+            .expect("Must be able to parse large counts");
+
+        assert_eq!(large.id(), 4_000_000_000);
+        assert_eq!(large.minimum_bag().power(), Some(300 * 70000));
+    }
+
+    #[test]
+    fn powers_overflow() {
+        const INPUT: &str = "Game 1: 4000000000 a, 4000000000 b, 4000000000 c, \
+                             4000000000 d, 4000000000 e";
+
+        let error = common::solve::<Puzzle>(INPUT, Part::Two).unwrap_err();
+
+        assert_eq!(error.message(), "Powers overflowed");
+
+        let games = Puzzle::default()
+            .parse(INPUT)
+            .expect("Must be able to parse large counts");
+
+        assert_eq!(
+            statistics(&games).unwrap_err().message(),
+            "Powers overflowed"
+        );
+        assert_eq!(
+            optimal_bag(&games, 1, Objective::Power)
+                .unwrap_err()
+                .message(),
+            "Bag power overflowed"
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let games = Puzzle::default()
+            .parse(Puzzle::EXAMPLE)
+            .expect("Must be able to parse example");
+
+        let json = to_json(&games);
+
+        assert!(json.contains(r#""id": 1"#));
+        assert_eq!(from_json(&json), Ok(games));
+        assert_eq!(common::solve::<Puzzle>(&json, Part::One), Ok(8));
+        assert_eq!(common::solve::<Puzzle>(&json, Part::Two), Ok(2286));
+
+        const INVALID: &str = "[\n  {\"id\": 1, \"rounds\": [[[\"red\", -1]]]}\n]";
+
+        let error = common::solve::<Puzzle>(INVALID, Part::One).unwrap_err();
+
+        assert_eq!(error.message(), "Could not parse JSON games");
+        assert_eq!(error.location().map(|(line, _)| line), Some(2));
+    }
+
+    #[test]
+    fn json_rejects_unprintable_games() {
+        let message = |json: &str| from_json(json).map_err(|error| error.message());
+
+        assert_eq!(
+            message(r#"[{"id": 1, "rounds": []}]"#),
+            Err("Game has no rounds")
+        );
+        assert_eq!(
+            message(r#"[{"id": 1, "rounds": [[]]}]"#),
+            Err("Round draws no cubes")
+        );
+
+        for colour in ["", "dark red", "red,", "red;", "red:"] {
+            let json = format!(r#"[{{"id": 1, "rounds": [[["{}", 1]]]}}]"#, colour);
+
+            assert_eq!(message(&json), Err("Invalid colour name"), "'{}'", colour);
+        }
+
+        assert_eq!(
+            message(r#"[{"id": 1, "rounds": [[["red", 1], ["red", 2]]]}]"#),
+            Err("Colour drawn twice in a round")
+        );
+
+        let game = "Game 1: 4000000000 red, 4000000000 blue"
+            .parse::<Game>()
+            .expect("Must parse game");
+
+        assert_eq!(game.rounds[0].total(), 8_000_000_000);
+    }
+}
@@ -1,162 +1,179 @@
-use clap::Parser;
+use std::process::ExitCode;
+
+use clap::{Parser, ValueEnum};
+use common::{Runner, Solution};
+use day_2::{Bag, Objective, Puzzle};
+
+#[global_allocator]
+static ALLOCATOR: common::profile::Allocator = common::profile::Allocator;
 
 /// Command arguments
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Input file from AoC
-    #[arg(short, long, default_value_t = String::from("inputs/day_2.txt"))]
-    input: String,
+    #[command(flatten)]
+    common: common::Args,
+
+    /// Cubes in the bag used by part 1, such as 'red=12,green=13,blue=14'
+    #[arg(long, value_parser = common::settings::parse_argument::<Bag>)]
+    bag: Option<Bag>,
+
+    /// File of 'colour=count' entries used instead of '--bag'
+    #[arg(long, conflicts_with = "bag")]
+    bag_file: Option<String>,
+
+    /// Print whether each game was possible with the bag instead of solving
+    #[arg(long)]
+    feasibility: bool,
+
+    /// Print the smallest bag with which at least this many games are possible instead of solving
+    #[arg(long, conflicts_with = "feasibility")]
+    admit: Option<usize>,
+
+    /// Measure of bag size minimised by '--admit'
+    #[arg(long, value_enum, default_value_t = Objective::Total)]
+    objective: Objective,
+
+    /// Print the parsed games of every input as one document in this format instead of solving
+    #[arg(long, value_enum, conflicts_with_all = ["feasibility", "admit"])]
+    emit: Option<Emit>,
+
+    /// Print statistics of each colour, the rounds per game and minimum bag powers in the
+    /// '--output' format instead of solving
+    #[arg(long, conflicts_with_all = ["feasibility", "admit", "emit"])]
+    stats: bool,
+
+    /// Print the most likely bag for each game, and for all games together, instead of solving
+    #[arg(long, conflicts_with_all = ["feasibility", "admit", "emit", "stats"])]
+    estimate: bool,
+
+    /// Largest number of cubes of a colour considered by '--estimate', at most 1000 since
+    /// the time taken grows with its cube
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..=1000))]
+    max_cubes: u32,
 }
 
-fn main() {
-    let args = Args::parse();
-
-    let input = std::fs::read_to_string(args.input).expect("must be able to read input file");
-
-    let result = solve_part_1(input.as_str());
-
-    println!("Part 1: {:?}", result);
-
-    let result = solve_part_2(input.as_str());
-
-    println!("Part 2: {:?}", result);
+/// Formats games can be printed in by '--emit'
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Emit {
+    /// Canonical "Game N: ..." lines
+    Text,
+    /// JSON, which can be read back as input
+    Json,
 }
 
-#[derive(Default, Debug)]
-struct Dice<'a> {
-    count: std::collections::HashMap<&'a str, u8>,
-}
+fn main() -> ExitCode {
+    let args = Args::parse();
 
-impl<'a> Dice<'a> {
-    fn power(&self) -> u32 {
-        self.count.values().map(|&value| value as u32).product()
-    }
+    let mut puzzle = Puzzle::default();
 
-    fn subset(&self, other: &Self) -> bool {
-        self.count.keys().all(|key| {
-            self.count.get(key).copied().unwrap_or_default()
-                <= other.count.get(key).copied().unwrap_or_default()
-        })
+    if let Some(bag) = args.bag {
+        puzzle.bag = bag;
     }
-}
-
-impl<'a> TryFrom<&'a str> for Dice<'a> {
-    type Error = ();
-
-    fn try_from(summary: &'a str) -> Result<Self, Self::Error> {
-        let mut round = Self::default();
-
-        for cubes in summary.split(',') {
-            let mut split = cubes.trim().split(' ');
-
-            let count = split.next().ok_or(())?.parse::<u8>().map_err(|_| ())?;
-
-            round.count.insert(split.next().ok_or(())?, count);
 
-            if split.next().is_some() {
-                return Err(());
+    if let Some(path) = &args.bag_file {
+        match common::settings::load::<Bag>(path) {
+            Ok(bag) => puzzle.bag = bag,
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
             }
         }
-
-        Ok(round)
     }
-}
 
-#[derive(Default, Debug)]
-struct Game<'a> {
-    id: u8,
-    rounds: Vec<Dice<'a>>,
-}
+    let format = args.common.output;
 
-impl<'a> TryFrom<&'a str> for Game<'a> {
-    type Error = ();
-
-    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let mut split = value.strip_prefix("Game").ok_or(())?.trim().split(':');
-
-        let id = split
-            .next()
-            .ok_or(())?
-            .trim()
-            .parse::<u8>()
-            .map_err(|_| ())?;
-
-        let rounds = split
-            .next()
-            .ok_or(())?
-            .split(';')
-            .map(str::trim)
-            .map(Dice::try_from)
-            .collect::<Result<Vec<_>, _>>()?;
-
-        if split.next().is_some() {
-            return Err(());
+    let mut runner = match Runner::new(args.common) {
+        Ok(runner) => runner,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
         }
+    };
 
-        Ok(Self { id, rounds })
-    }
-}
+    if args.feasibility {
+        runner.each_input(Puzzle::DAY, |path, input| {
+            let games = puzzle.parse(input)?;
 
-impl<'a> Game<'a> {
-    fn minimum_bag(&self) -> Dice {
-        self.rounds.iter().fold(Dice::default(), |mut bag, round| {
-            for (&colour, &count) in round.count.iter() {
-                let old_count = bag.count.entry(colour).or_default();
-                *old_count = (*old_count).max(count);
-            }
+            println!("{}:", path);
+            print!("{}", day_2::feasibility(&games, &puzzle.bag));
 
-            bag
-        })
-    }
-}
+            Ok(())
+        });
+    } else if let Some(admit) = args.admit {
+        runner.each_input(Puzzle::DAY, |path, input| {
+            let games = puzzle.parse(input)?;
 
-fn solve_part_1(input: &str) -> Option<u128> {
-    let bag = Dice {
-        count: vec![("red", 12), ("green", 13), ("blue", 14)]
-            .into_iter()
-            .collect(),
-    };
+            println!("{}:", path);
 
-    input
-        .lines()
-        .map(|line| Game::try_from(line).ok())
-        .filter(|g| !g.as_ref().is_some_and(|g| !g.minimum_bag().subset(&bag)))
-        .try_fold(0, |x, game| Some(game?.id as u128 + x))
-}
+            match day_2::optimal_bag(&games, admit, args.objective)? {
+                Some(optimum) => print!("{}", optimum),
+                None => println!("Only {} games to admit", games.len()),
+            }
 
-fn solve_part_2(input: &str) -> Option<u128> {
-    input
-        .lines()
-        .map(|line| Some(Game::try_from(line).ok()?.minimum_bag().power()))
-        .try_fold(0, |x, power| Some(power? as u128 + x))
-}
+            Ok(())
+        });
+    } else if let Some(emit) = args.emit {
+        let mut games = Vec::new();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        runner.each_input(Puzzle::DAY, |_, input| {
+            games.extend(puzzle.parse(input)?);
 
-    #[test]
-    fn example_part_1() {
-        const INPUT: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
-        const RESULT: Option<u128> = Some(8);
+            Ok(())
+        });
 
-        assert_eq!(solve_part_1(INPUT), RESULT);
-    }
+        match emit {
+            Emit::Text => games.iter().for_each(|game| println!("{}", game)),
+            Emit::Json => println!("{}", day_2::to_json(&games)),
+        }
+    } else if args.stats {
+        let mut inputs = Vec::new();
+
+        runner.each_input(Puzzle::DAY, |path, input| {
+            let games = puzzle.parse(input)?;
+
+            inputs.push((path.to_string(), day_2::statistics(&games)?));
+
+            Ok(())
+        });
+
+        print!("{}", day_2::render(&inputs, format));
+    } else if args.estimate {
+        runner.each_input(Puzzle::DAY, |path, input| {
+            let games = puzzle.parse(input)?;
+
+            println!("{}:", path);
+
+            let show = |name: String, estimate: Result<Option<day_2::Estimate>, common::Error>| {
+                match estimate {
+                    Ok(Some(estimate)) => println!("{}: {}", name, estimate),
+                    Ok(None) => {}
+                    Err(error) => println!(
+                        "{}: {}: {} (--max-cubes {})",
+                        name,
+                        error.message(),
+                        error.snippet(),
+                        args.max_cubes
+                    ),
+                }
+            };
+
+            for game in &games {
+                let estimate = day_2::estimate(game.rounds(), args.max_cubes);
+                show(format!("Game {}", game.id()), estimate);
+            }
 
-    #[test]
-    fn example_part_2() {
-        const INPUT: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"#;
-        const RESULT: Option<u128> = Some(2286);
+            let rounds = games.iter().flat_map(|game| game.rounds());
+            show(
+                "All games".to_string(),
+                day_2::estimate(rounds, args.max_cubes),
+            );
 
-        assert_eq!(solve_part_2(INPUT), RESULT);
+            Ok(())
+        });
+    } else {
+        runner.run_with(&puzzle);
     }
+
+    runner.finish()
 }
@@ -0,0 +1,294 @@
+use std::fmt::Display;
+
+use common::Error;
+
+use crate::{Bag, Colour, Game};
+
+/// Measure of bag size minimised by [`optimal_bag`].
+#[derive(clap::ValueEnum, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    /// Total number of cubes.
+    #[default]
+    Total,
+    /// Product of the number of cubes of each colour.
+    Power,
+}
+
+impl Objective {
+    /// Size of a bag with these counts, or `None` if it overflows.
+    fn measure(&self, counts: &[u32]) -> Option<u128> {
+        match self {
+            Self::Total => Some(counts.iter().map(|&count| count as u128).sum()),
+            Self::Power => counts
+                .iter()
+                .filter(|&&count| count > 0)
+                .try_fold(1u128, |power, &count| power.checked_mul(count as u128)),
+        }
+    }
+}
+
+/// Smallest bag admitting enough games, and the games it admits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Optimum {
+    pub bag: Bag,
+    /// IDs of every game possible with the bag, in input order.
+    pub games: Vec<u32>,
+}
+
+impl Display for Optimum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let games = self
+            .games
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+
+        let power = match self.bag.power() {
+            Some(power) => power.to_string(),
+            None => "too large".to_string(),
+        };
+
+        writeln!(
+            f,
+            "Bag: {} (total {}, power {})",
+            self.bag,
+            self.bag.total(),
+            power
+        )?;
+        writeln!(f, "Admits {} games: {}", games.len(), games.join(", "))
+    }
+}
+
+/// Find the bag minimising `objective` with which at least `admit` games are possible.
+///
+/// Every bag worth considering holds, for each colour, as many cubes as some
+/// game needs. Thresholds are tried for all colours but the last, which then
+/// only needs to hold as many cubes as the `admit`-th smallest remaining game.
+///
+/// Returns `None` if there are fewer than `admit` games, or an error if the
+/// objective of a bag overflows.
+pub fn optimal_bag(
+    games: &[Game],
+    admit: usize,
+    objective: Objective,
+) -> Result<Option<Optimum>, Error> {
+    if admit > games.len() {
+        return Ok(None);
+    }
+
+    let mut colours = Vec::new();
+    let minimum_bags = games.iter().map(Game::minimum_bag).collect::<Vec<_>>();
+
+    for bag in &minimum_bags {
+        for (colour, _) in &bag.count {
+            if !colours.contains(colour) {
+                colours.push(colour.clone());
+            }
+        }
+    }
+
+    let needs = minimum_bags
+        .iter()
+        .map(|bag| {
+            colours
+                .iter()
+                .map(|colour| bag.get(colour.as_str()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let thresholds = (0..colours.len())
+        .map(|colour| {
+            let mut values = needs.iter().map(|need| need[colour]).collect::<Vec<_>>();
+            values.push(0);
+            values.sort_unstable();
+            values.dedup();
+            values
+        })
+        .collect();
+
+    let mut search = Search {
+        needs: &needs,
+        thresholds,
+        admit,
+        objective,
+        best: None,
+    };
+
+    if admit == 0 || colours.is_empty() {
+        search.consider(&vec![0; colours.len()])?;
+    } else {
+        search.visit(&mut Vec::new(), (0..games.len()).collect())?;
+    }
+
+    let Some((_, counts, admitted)) = search.best else {
+        return Ok(None);
+    };
+
+    Ok(Some(Optimum {
+        bag: colours.iter().map(Colour::as_str).zip(counts).collect(),
+        games: admitted.into_iter().map(|index| games[index].id).collect(),
+    }))
+}
+
+struct Search<'a> {
+    needs: &'a [Vec<u32>],
+    thresholds: Vec<Vec<u32>>,
+    admit: usize,
+    objective: Objective,
+    best: Option<(u128, Vec<u32>, Vec<usize>)>,
+}
+
+impl Search<'_> {
+    /// Try thresholds for the next colour of `bag`, among the games fitting it so far.
+    fn visit(&mut self, bag: &mut Vec<u32>, games: Vec<usize>) -> Result<(), Error> {
+        if games.len() < self.admit {
+            return Ok(());
+        }
+
+        let colour = bag.len();
+
+        if colour + 1 == self.thresholds.len() {
+            let mut counts = games
+                .iter()
+                .map(|&game| self.needs[game][colour])
+                .collect::<Vec<_>>();
+
+            counts.sort_unstable();
+
+            bag.push(counts[self.admit - 1]);
+            self.consider(bag)?;
+            bag.pop();
+
+            return Ok(());
+        }
+
+        for index in 0..self.thresholds[colour].len() {
+            let threshold = self.thresholds[colour][index];
+
+            let fitting = games
+                .iter()
+                .copied()
+                .filter(|&game| self.needs[game][colour] <= threshold)
+                .collect::<Vec<_>>();
+
+            let all = fitting.len() == games.len();
+
+            bag.push(threshold);
+            self.visit(bag, fitting)?;
+            bag.pop();
+
+            if all {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keep the bag if it is the smallest so far, once shrunk to fit the games it admits.
+    fn consider(&mut self, bag: &[u32]) -> Result<(), Error> {
+        let admitted = (0..self.needs.len())
+            .filter(|&game| {
+                self.needs[game]
+                    .iter()
+                    .zip(bag)
+                    .all(|(need, have)| need <= have)
+            })
+            .collect::<Vec<_>>();
+
+        if admitted.len() < self.admit {
+            return Ok(());
+        }
+
+        let mut counts = vec![0; bag.len()];
+
+        for &game in &admitted {
+            for (count, &need) in counts.iter_mut().zip(&self.needs[game]) {
+                *count = (*count).max(need);
+            }
+        }
+
+        let value = self
+            .objective
+            .measure(&counts)
+            .ok_or_else(|| Error::from("Bag power overflowed"))?;
+
+        if self.best.as_ref().is_none_or(|(best, ..)| value < *best) {
+            self.best = Some((value, counts, admitted));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    /// Smallest objective over every subset of at least `admit` games.
+    fn brute_force(games: &[Game], admit: usize, objective: Objective) -> Option<u128> {
+        let colours = ["red", "green", "blue"];
+
+        (0u32..1 << games.len())
+            .filter(|subset| subset.count_ones() as usize >= admit)
+            .map(|subset| {
+                let counts = colours
+                    .iter()
+                    .map(|&colour| {
+                        (0..games.len())
+                            .filter(|&game| subset & (1 << game) != 0)
+                            .map(|game| games[game].minimum_bag().get(colour))
+                            .max()
+                            .unwrap_or_default()
+                    })
+                    .collect::<Vec<_>>();
+
+                objective
+                    .measure(&counts)
+                    .expect("Example bags must not overflow")
+            })
+            .min()
+    }
+
+    #[test]
+    fn example_optimum() {
+        let games = Puzzle::default()
+            .parse(Puzzle::EXAMPLE)
+            .expect("Must be able to parse example");
+
+        let optimum = optimal_bag(&games, 3, Objective::Total)
+            .expect("Must search bags")
+            .expect("Must find a bag");
+
+        assert_eq!(optimum.bag.to_string(), "blue=6,red=6,green=3");
+        assert_eq!(optimum.games, vec![1, 2, 5]);
+        assert_eq!(optimal_bag(&games, 6, Objective::Total), Ok(None));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let games = Puzzle::default()
+            .parse(Puzzle::EXAMPLE)
+            .expect("Must be able to parse example");
+
+        for objective in [Objective::Total, Objective::Power] {
+            for admit in 1..=games.len() {
+                let optimum = optimal_bag(&games, admit, objective)
+                    .expect("Must search bags")
+                    .expect("Must find a bag");
+
+                let value = match objective {
+                    Objective::Total => optimum.bag.total(),
+                    Objective::Power => optimum.bag.power().expect("Example power must fit"),
+                };
+
+                assert!(optimum.games.len() >= admit);
+                assert_eq!(Some(value), brute_force(&games, admit, objective));
+            }
+        }
+    }
+}
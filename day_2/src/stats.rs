@@ -0,0 +1,219 @@
+use common::{output, Error, Format};
+use serde::Serialize;
+
+use crate::{Colour, Game};
+
+/// Percentiles reported for each metric.
+const PERCENTILES: [usize; 5] = [10, 25, 50, 75, 90];
+
+/// Distribution of a single metric across the games.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Summary {
+    pub metric: String,
+    /// Number of values summarised.
+    pub count: usize,
+    pub min: u128,
+    pub p10: u128,
+    pub p25: u128,
+    pub p50: u128,
+    pub p75: u128,
+    pub p90: u128,
+    pub max: u128,
+    pub mean: f64,
+}
+
+impl Summary {
+    const HEADER: [&'static str; 10] = [
+        "metric", "count", "min", "p10", "p25", "p50", "p75", "p90", "max", "mean",
+    ];
+
+    /// Summarise the values, or `None` if there are none.
+    fn of(metric: impl Into<String>, mut values: Vec<u128>) -> Option<Self> {
+        values.sort_unstable();
+
+        let (&min, &max) = (values.first()?, values.last()?);
+
+        // Nearest-rank percentile
+        let percentile = |p: usize| values[(p * values.len()).div_ceil(100).max(1) - 1];
+        let [p10, p25, p50, p75, p90] = PERCENTILES.map(percentile);
+
+        Some(Self {
+            metric: metric.into(),
+            count: values.len(),
+            min,
+            p10,
+            p25,
+            p50,
+            p75,
+            p90,
+            max,
+            mean: values.iter().map(|&value| value as f64).sum::<f64>() / values.len() as f64,
+        })
+    }
+
+    fn cells(&self) -> Vec<String> {
+        [
+            self.metric.clone(),
+            self.count.to_string(),
+            self.min.to_string(),
+            self.p10.to_string(),
+            self.p25.to_string(),
+            self.p50.to_string(),
+            self.p75.to_string(),
+            self.p90.to_string(),
+            self.max.to_string(),
+            format!("{:.2}", self.mean),
+        ]
+        .into()
+    }
+}
+
+/// Summaries of the cubes of each colour drawn in a round, the number of
+/// rounds in each game, and the power of each game's minimum bag.
+///
+/// Colours are summarised over the rounds which drew them, in the order they
+/// first appear. Fails if the power of a minimum bag overflows.
+pub fn statistics(games: &[Game]) -> Result<Vec<Summary>, Error> {
+    let mut colours: Vec<(Colour, Vec<u128>)> = Vec::new();
+
+    for round in games.iter().flat_map(|game| game.rounds.iter()) {
+        for (colour, count) in &round.count {
+            match colours.iter_mut().find(|(name, _)| name == colour) {
+                Some((_, counts)) => counts.push(*count as u128),
+                None => colours.push((colour.clone(), vec![*count as u128])),
+            }
+        }
+    }
+
+    let rounds = games.iter().map(|game| game.rounds.len() as u128).collect();
+    let powers = games
+        .iter()
+        .map(|game| game.minimum_bag().power())
+        .collect::<Option<_>>()
+        .ok_or_else(|| Error::from("Powers overflowed"))?;
+
+    Ok(colours
+        .into_iter()
+        .filter_map(|(colour, counts)| Summary::of(format!("{} per round", colour), counts))
+        .chain(Summary::of("rounds per game", rounds))
+        .chain(Summary::of("minimum bag power", powers))
+        .collect())
+}
+
+/// Summaries of the games in an input file, serialized with its path.
+#[derive(Serialize)]
+struct Row<'a> {
+    path: &'a str,
+    #[serde(flatten)]
+    summary: &'a Summary,
+}
+
+/// Render the summaries of each input file as tables under their paths, or
+/// as a single JSON or CSV document with a path on each row.
+pub fn render(inputs: &[(String, Vec<Summary>)], format: Format) -> String {
+    let rows = || {
+        inputs.iter().flat_map(|(path, summaries)| {
+            summaries.iter().map(move |summary| Row { path, summary })
+        })
+    };
+
+    match format {
+        Format::Text => {
+            let header = Summary::HEADER.map(String::from);
+
+            inputs
+                .iter()
+                .map(|(path, summaries)| {
+                    let rows = summaries.iter().map(Summary::cells).collect::<Vec<_>>();
+
+                    format!("{}:\n{}", path, output::columns(&header, &rows))
+                })
+                .collect()
+        }
+        Format::Json => {
+            serde_json::to_string_pretty(&rows().collect::<Vec<_>>())
+                .expect("Summaries must serialize")
+                + "\n"
+        }
+        Format::Csv => std::iter::once(format!("path,{}", Summary::HEADER.join(",")))
+            .chain(rows().map(|row| {
+                std::iter::once(row.path.to_string())
+                    .chain(row.summary.cells())
+                    .map(|cell| output::csv_field(&cell))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }))
+            .map(|line| line + "\n")
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn example_statistics() {
+        let games = Puzzle::default()
+            .parse(Puzzle::EXAMPLE)
+            .expect("Must be able to parse example");
+
+        let summaries = statistics(&games).expect("Example powers must fit");
+
+        let metrics = summaries
+            .iter()
+            .map(|summary| summary.metric.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            metrics,
+            [
+                "blue per round",
+                "red per round",
+                "green per round",
+                "rounds per game",
+                "minimum bag power"
+            ]
+        );
+
+        let red = &summaries[1];
+        assert_eq!((red.count, red.min, red.p50, red.max), (11, 1, 4, 20));
+        assert_eq!(format!("{:.2}", red.mean), "5.55");
+
+        let rounds = &summaries[3];
+        assert_eq!((rounds.min, rounds.p50, rounds.max), (2, 3, 3));
+
+        let powers = &summaries[4];
+        assert_eq!((powers.min, powers.max), (12, 1560));
+    }
+
+    #[test]
+    fn render_csv() {
+        let inputs = [
+            (
+                "a.txt".to_string(),
+                vec![Summary::of("red, per round", vec![4, 1, 3]).unwrap()],
+            ),
+            (
+                "b.txt".to_string(),
+                vec![Summary::of("rounds per game", vec![2]).unwrap()],
+            ),
+        ];
+
+        assert_eq!(
+            render(&inputs, Format::Csv),
+            "path,metric,count,min,p10,p25,p50,p75,p90,max,mean\n\
+             a.txt,\"red, per round\",3,1,1,1,3,4,4,4,2.67\n\
+             b.txt,rounds per game,1,2,2,2,2,2,2,2,2.00\n"
+        );
+
+        let json = serde_json::from_str::<serde_json::Value>(&render(&inputs, Format::Json))
+            .expect("Must render a single JSON document");
+
+        assert_eq!(json[1]["path"], "b.txt");
+        assert_eq!(json[1]["metric"], "rounds per game");
+    }
+}
@@ -0,0 +1,318 @@
+use std::{collections::HashMap, fmt::Write, ops::RangeInclusive};
+
+use common::Position;
+use serde::Serialize;
+
+use crate::{Entry, Schematic};
+
+/// A number in the schematic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartNode {
+    pub value: usize,
+    /// Columns and row the number is written across.
+    pub location: (RangeInclusive<usize>, usize),
+}
+
+/// A symbol in the schematic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SymbolNode {
+    pub symbol: char,
+    pub position: Position,
+}
+
+/// Bipartite graph of numbers and the symbols they touch, including diagonally.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Graph {
+    pub parts: Vec<PartNode>,
+    pub symbols: Vec<SymbolNode>,
+    /// Indices into `parts` and `symbols` of each adjacent pair.
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// Numbers and symbols connected through adjacency, as indices into the graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    pub parts: Vec<usize>,
+    pub symbols: Vec<usize>,
+}
+
+impl Graph {
+    pub fn new(schematic: &Schematic) -> Self {
+        let mut symbols = Vec::new();
+        let mut indices = HashMap::new();
+        let mut edges = Vec::new();
+
+        let parts = schematic
+            .numbers
+            .iter()
+            .enumerate()
+            .map(|(part, number)| {
+                for (position, symbol) in schematic.symbols_around(number) {
+                    let index = *indices.entry(position).or_insert_with(|| {
+                        symbols.push(SymbolNode { symbol, position });
+                        symbols.len() - 1
+                    });
+
+                    edges.push((part, index));
+                }
+
+                PartNode {
+                    value: number.value,
+                    location: (number.columns.clone(), number.row),
+                }
+            })
+            .collect();
+
+        // Symbols touching no number are still nodes
+        for (position, entry) in schematic.entries.iter() {
+            if let Entry::Symbol(symbol) = *entry {
+                indices.entry(position).or_insert_with(|| {
+                    symbols.push(SymbolNode { symbol, position });
+                    symbols.len() - 1
+                });
+            }
+        }
+
+        Self {
+            parts,
+            symbols,
+            edges,
+        }
+    }
+
+    /// Connected components, ordered by their first number, then by their
+    /// symbol for symbols touching no number.
+    pub fn components(&self) -> Vec<Component> {
+        let offset = self.parts.len();
+        let mut roots = (0..offset + self.symbols.len()).collect::<Vec<_>>();
+
+        fn root(roots: &mut [usize], mut node: usize) -> usize {
+            while roots[node] != node {
+                roots[node] = roots[roots[node]];
+                node = roots[node];
+            }
+            node
+        }
+
+        for &(part, symbol) in &self.edges {
+            let (a, b) = (root(&mut roots, part), root(&mut roots, offset + symbol));
+            roots[a.max(b)] = a.min(b);
+        }
+
+        let mut components: Vec<Component> = Vec::new();
+        let mut slots = HashMap::new();
+
+        for node in 0..roots.len() {
+            let slot = *slots.entry(root(&mut roots, node)).or_insert_with(|| {
+                components.push(Component {
+                    parts: Vec::new(),
+                    symbols: Vec::new(),
+                });
+                components.len() - 1
+            });
+
+            if node < offset {
+                components[slot].parts.push(node);
+            } else {
+                components[slot].symbols.push(node - offset);
+            }
+        }
+
+        components
+    }
+
+    /// Indices of the numbers touching no symbol.
+    pub fn orphans(&self) -> Vec<usize> {
+        let mut touching = vec![false; self.parts.len()];
+
+        for &(part, _) in &self.edges {
+            touching[part] = true;
+        }
+
+        (0..self.parts.len())
+            .filter(|&part| !touching[part])
+            .collect()
+    }
+
+    /// Graphviz DOT, with numbers as boxes named by their first cell and
+    /// symbols as circles named by their cell.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph schematic {\n");
+
+        let part_id = |part: &PartNode| format!("p{}_{}", part.location.1, part.location.0.start());
+        let symbol_id =
+            |symbol: &SymbolNode| format!("s{}_{}", symbol.position.0, symbol.position.1);
+
+        for part in &self.parts {
+            let _ = writeln!(
+                dot,
+                "    {} [label=\"{}\", shape=box];",
+                part_id(part),
+                part.value
+            );
+        }
+
+        for symbol in &self.symbols {
+            let label = match symbol.symbol {
+                '"' | '\\' => format!("\\{}", symbol.symbol),
+                symbol => symbol.to_string(),
+            };
+
+            let _ = writeln!(
+                dot,
+                "    {} [label=\"{}\", shape=circle];",
+                symbol_id(symbol),
+                label
+            );
+        }
+
+        for &(part, symbol) in &self.edges {
+            let _ = writeln!(
+                dot,
+                "    {} -- {};",
+                part_id(&self.parts[part]),
+                symbol_id(&self.symbols[symbol])
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Graph must serialize")
+    }
+
+    /// Summary of the graph, each component joining numbers and symbols, and
+    /// the orphan numbers.
+    pub fn report(&self) -> String {
+        let mut report = String::new();
+
+        let components = self.components();
+        let connected = components
+            .iter()
+            .filter(|component| !component.parts.is_empty() && !component.symbols.is_empty())
+            .collect::<Vec<_>>();
+
+        let _ = writeln!(
+            report,
+            "{} numbers, {} symbols, {} edges, {} connected components",
+            self.parts.len(),
+            self.symbols.len(),
+            self.edges.len(),
+            connected.len()
+        );
+
+        for component in connected {
+            let symbols = component
+                .symbols
+                .iter()
+                .map(|&symbol| {
+                    let symbol = &self.symbols[symbol];
+                    format!("'{}' at {:?}", symbol.symbol, symbol.position)
+                })
+                .collect::<Vec<_>>();
+
+            let values = component
+                .parts
+                .iter()
+                .map(|&part| self.parts[part].value.to_string())
+                .collect::<Vec<_>>();
+
+            let _ = writeln!(report, "  {}: {}", symbols.join(", "), values.join(", "));
+        }
+
+        let orphans = self
+            .orphans()
+            .into_iter()
+            .map(|part| {
+                let part = &self.parts[part];
+                let (columns, row) = &part.location;
+                format!("{} at ({}, {:?})", part.value, row, columns)
+            })
+            .collect::<Vec<_>>();
+
+        let _ = writeln!(report, "{} orphan numbers", orphans.len());
+
+        for orphan in orphans {
+            let _ = writeln!(report, "  {}", orphan);
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    fn example() -> Graph {
+        let schematic = Puzzle::default()
+            .parse(Puzzle::EXAMPLE)
+            .expect("Must parse example");
+
+        Graph::new(&schematic)
+    }
+
+    #[test]
+    fn example_components() {
+        let graph = example();
+
+        assert_eq!((graph.parts.len(), graph.symbols.len()), (10, 6));
+        assert_eq!(graph.edges.len(), 8);
+
+        let orphans = graph
+            .orphans()
+            .into_iter()
+            .map(|part| graph.parts[part].value)
+            .collect::<Vec<_>>();
+
+        assert_eq!(orphans, vec![114, 58]);
+
+        let components = graph
+            .components()
+            .into_iter()
+            .map(|component| {
+                component
+                    .parts
+                    .iter()
+                    .map(|&part| graph.parts[part].value)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            components,
+            vec![
+                vec![467, 35],
+                vec![114],
+                vec![633],
+                vec![617],
+                vec![58],
+                vec![592],
+                vec![755, 598],
+                vec![664],
+            ]
+        );
+    }
+
+    #[test]
+    fn export_dot() {
+        let schematic = "12.\n.*.\n..#"
+            .parse::<Schematic>()
+            .expect("Must parse schematic");
+
+        assert_eq!(
+            Graph::new(&schematic).to_dot(),
+            "graph schematic {\n    \
+             p0_0 [label=\"12\", shape=box];\n    \
+             s1_1 [label=\"*\", shape=circle];\n    \
+             s2_2 [label=\"#\", shape=circle];\n    \
+             p0_0 -- s1_1;\n\
+             }\n"
+        );
+    }
+}
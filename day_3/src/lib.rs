@@ -0,0 +1,348 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use common::{Error, Grid, Part, Position, Solution};
+
+mod graph;
+mod render;
+mod rule;
+mod stream;
+
+pub use graph::{Component, Graph, PartNode, SymbolNode};
+pub use render::Role;
+pub use rule::{Combine, GearRule, Neighbourhood};
+pub use stream::{stream, totals, Event, Totals};
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    Symbol(char),
+    Value(u8),
+    #[default]
+    Blank,
+}
+
+impl TryFrom<char> for Entry {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(if let Some(digit) = value.to_digit(10) {
+            Self::Value(digit as u8)
+        } else if value == '.' {
+            Self::Blank
+        } else {
+            Self::Symbol(value)
+        })
+    }
+}
+
+/// A number written across consecutive columns of a single row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Number {
+    row: usize,
+    columns: RangeInclusive<usize>,
+    value: usize,
+}
+
+impl Number {
+    fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.columns.clone().map(|column| (self.row, column))
+    }
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    entries: Grid<Entry>,
+    numbers: Vec<Number>,
+    /// Index into `numbers` of the number written in each cell.
+    owners: Grid<Option<u32>>,
+}
+
+impl Schematic {
+    /// Numbers touching the position, each listed once.
+    fn numbers_around(
+        &self,
+        position: Position,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = &Number> {
+        let neighbours = match neighbourhood {
+            Neighbourhood::Four => self.entries.neighbours_4(position).collect::<Vec<_>>(),
+            Neighbourhood::Eight => self.entries.neighbours_8(position).collect(),
+        };
+
+        let mut indices = neighbours
+            .into_iter()
+            .filter_map(|position| self.owners[position])
+            .collect::<Vec<_>>();
+
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices
+            .into_iter()
+            .map(|index| &self.numbers[index as usize])
+    }
+
+    /// Symbols which are gears under the rule, with the numbers next to them.
+    fn gears<'a>(
+        &'a self,
+        rule: &'a GearRule,
+    ) -> impl Iterator<Item = (Position, Vec<&'a Number>)> + 'a {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| matches!(entry, Entry::Symbol(symbol) if rule.is_gear(*symbol)))
+            .filter_map(|(position, _)| {
+                let parts = self
+                    .numbers_around(position, rule.neighbourhood)
+                    .take(rule.max.saturating_add(1))
+                    .collect::<Vec<_>>();
+
+                rule.admits(parts.len()).then_some((position, parts))
+            })
+    }
+
+    /// Graph of the numbers and the symbols they touch.
+    pub fn graph(&self) -> Graph {
+        Graph::new(self)
+    }
+
+    /// Symbols touching the number, including diagonally.
+    fn symbols_around<'a>(
+        &'a self,
+        number: &'a Number,
+    ) -> impl Iterator<Item = (Position, char)> + 'a {
+        let rows = number.row.saturating_sub(1)..=number.row + 1;
+        let columns = number.columns.start().saturating_sub(1)..=number.columns.end() + 1;
+
+        rows.flat_map(move |row| columns.clone().map(move |column| (row, column)))
+            .filter_map(|position| match self.entries.get(position) {
+                Some(&Entry::Symbol(symbol)) => Some((position, symbol)),
+                _ => None,
+            })
+    }
+}
+
+/// Numbers written in a row of cells, from left to right.
+fn numbers_in(row: usize, cells: &[Entry]) -> Vec<Number> {
+    let mut numbers = Vec::new();
+    let mut active: Option<Number> = None;
+
+    for (column, &entry) in cells.iter().enumerate() {
+        active = match (entry, active.take()) {
+            (Entry::Value(digit), None) => Some(Number {
+                row,
+                columns: column..=column,
+                value: digit as usize,
+            }),
+            (Entry::Value(digit), Some(number)) => Some(Number {
+                columns: *number.columns.start()..=column,
+                value: 10 * number.value + digit as usize,
+                ..number
+            }),
+            (_, Some(number)) => {
+                numbers.push(number);
+                None
+            }
+            (_, None) => None,
+        };
+    }
+
+    numbers.extend(active);
+    numbers
+}
+
+impl FromStr for Schematic {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s.parse::<Grid<Entry>>()?;
+
+        let numbers = entries
+            .rows()
+            .enumerate()
+            .flat_map(|(row, cells)| numbers_in(row, cells))
+            .collect::<Vec<_>>();
+
+        let mut owners = entries.map(|_| None);
+
+        for (index, number) in numbers.iter().enumerate() {
+            for position in number.positions() {
+                owners[position] = Some(index as u32);
+            }
+        }
+
+        Ok(Self {
+            entries,
+            numbers,
+            owners,
+        })
+    }
+}
+
+/// Schematic reader, finding gears by its rule.
+#[derive(Default, Debug, Clone)]
+pub struct Puzzle {
+    /// Rule used by part 2.
+    pub gear: GearRule,
+}
+
+impl Solution for Puzzle {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+    const EXAMPLE: &'static str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+
+    type Input<'a> = Schematic;
+    type Answer = u128;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input.parse()
+    }
+
+    fn part_1(&self, schematic: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        let sum = schematic
+            .numbers
+            .iter()
+            .filter(|number| schematic.symbols_around(number).next().is_some())
+            .map(|number| number.value as u128)
+            .sum();
+
+        Ok(sum)
+    }
+
+    fn part_2(&self, schematic: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        schematic
+            .gears(&self.gear)
+            .map(|(_, parts)| {
+                let values = parts
+                    .iter()
+                    .map(|number| number.value as u128)
+                    .collect::<Vec<_>>();
+
+                self.gear.combine.apply(&values)
+            })
+            .try_fold(0u128, |sum, ratio| sum.checked_add(ratio?))
+            .ok_or_else(|| Error::from("Gear ratios overflowed"))
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn solve_part_2(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1() {
+        const INPUT: &str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+        const RESULT: Option<u128> = Some(4361);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_part_2() {
+        const INPUT: &str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+        const RESULT: Option<u128> = Some(467835);
+
+        assert_eq!(solve_part_2(INPUT), RESULT);
+    }
+
+    #[test]
+    fn neighbourhood_queries() {
+        const INPUT: &str = r#"12.4
+*5..
+..#6"#;
+
+        let schematic = INPUT.parse::<Schematic>().expect("Must parse schematic");
+
+        let around_star = schematic
+            .numbers_around((1, 0), Neighbourhood::Eight)
+            .map(|number| number.value)
+            .collect::<Vec<_>>();
+
+        assert_eq!(around_star, vec![12, 5]);
+
+        let symbols = schematic
+            .numbers
+            .iter()
+            .map(|number| {
+                (
+                    number.value,
+                    schematic.symbols_around(number).collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            symbols,
+            vec![
+                (12, vec![((1, 0), '*')]),
+                (4, vec![]),
+                (5, vec![((1, 0), '*'), ((2, 2), '#')]),
+                (6, vec![((2, 2), '#')]),
+            ]
+        );
+    }
+
+    #[test]
+    fn gear_rules() {
+        const INPUT: &str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#;
+
+        let solve = |rule: &str| {
+            let puzzle = Puzzle {
+                gear: rule.parse().expect("Must parse gear rule"),
+            };
+
+            common::solve_with(&puzzle, INPUT, Part::Two)
+        };
+
+        assert_eq!(solve(""), Ok(467835));
+        assert_eq!(solve("combine=sum"), Ok(467 + 35 + 755 + 598));
+        assert_eq!(solve("min=1, combine=max"), Ok(467 + 617 + 755));
+        assert_eq!(solve("symbols=#$, count=1"), Ok(633 + 664));
+        assert_eq!(
+            solve("symbols=*, count=1, neighbourhood=4"),
+            Ok(35 + 617 + 598)
+        );
+    }
+}
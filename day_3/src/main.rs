@@ -1,182 +1,151 @@
-use std::{collections::HashMap, convert::Infallible, ops::RangeInclusive, str::FromStr};
+use std::process::ExitCode;
 
-use clap::Parser;
+use clap::{error::ErrorKind, CommandFactory, Parser, ValueEnum};
+use common::{Runner, Solution};
+use day_3::{GearRule, Puzzle};
+use image::ImageFormat;
+
+#[global_allocator]
+static ALLOCATOR: common::profile::Allocator = common::profile::Allocator;
 
 /// Command arguments
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Input file from AoC
-    #[arg(short, long, default_value_t = String::from("inputs/day_3.txt"))]
-    input: String,
+    #[command(flatten)]
+    common: common::Args,
+
+    /// Rule for which symbols are gears used by part 2, such as 'symbols=*,count=2,combine=product'
+    #[arg(long, value_parser = common::settings::parse_argument::<GearRule>)]
+    gear_rule: Option<GearRule>,
+
+    /// File of 'key=value' gear rule entries used instead of '--gear-rule'
+    #[arg(long, conflicts_with = "gear_rule")]
+    gear_rule_file: Option<String>,
+
+    /// Print the graph of numbers and the symbols they touch in this format instead of solving
+    #[arg(long, value_enum)]
+    graph: Option<GraphFormat>,
+
+    /// Print the connected numbers and symbols, and the numbers touching no symbol, instead of
+    /// solving
+    #[arg(long, conflicts_with = "graph")]
+    components: bool,
+
+    /// Print the schematic coloured by which numbers are parts and which symbols are gears
+    /// instead of solving
+    #[arg(long, conflicts_with_all = ["graph", "components"])]
+    render: bool,
+
+    /// Save an image of the schematic coloured like '--render' to this PNG file instead of
+    /// solving, for a single input
+    #[arg(long, conflicts_with_all = ["graph", "components", "render"])]
+    png: Option<String>,
+
+    /// Width in pixels of the square drawn for each cell by '--png'
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+    cell_size: u32,
+
+    /// Answer both parts in a single pass, reading a line at a time rather than holding the whole
+    /// schematic in memory
+    #[arg(long, conflicts_with_all = ["graph", "components", "render", "png", "mmap"])]
+    stream: bool,
 }
 
-fn main() {
-    let args = Args::parse();
-
-    let input = std::fs::read_to_string(args.input).expect("must be able to read input file");
+/// Formats the graph can be printed in by '--graph'
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// JSON
+    Json,
+}
 
-    let result = solve_part_1(input.as_str());
+fn main() -> ExitCode {
+    let args = Args::parse();
 
-    println!("Part 1: {:?}", result);
+    if args.png.is_some() && args.common.input_files().count() > 1 {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "'--png' saves a single image, so cannot be used with more than one input",
+            )
+            .exit();
+    }
 
-    let result = solve_part_2(input.as_str());
+    let mut puzzle = Puzzle::default();
 
-    println!("Part 2: {:?}", result);
-}
-
-#[derive(Default, Debug, Clone, Copy)]
-enum Entry {
-    Symbol(char),
-    Value(u8),
-    #[default]
-    Blank,
-}
+    if let Some(rule) = args.gear_rule {
+        puzzle.gear = rule;
+    }
 
-impl From<char> for Entry {
-    fn from(value: char) -> Self {
-        if let Some(digit) = value.to_digit(10) {
-            Self::Value(digit as u8)
-        } else if value == '.' {
-            Self::Blank
-        } else {
-            Self::Symbol(value)
+    if let Some(path) = &args.gear_rule_file {
+        match common::settings::load::<GearRule>(path) {
+            Ok(rule) => puzzle.gear = rule,
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitCode::FAILURE;
+            }
         }
     }
-}
 
-#[derive(Default, Debug)]
-struct Schematic {
-    parts: HashMap<(RangeInclusive<usize>, usize), usize>,
-    symbols: HashMap<(usize, usize), char>,
-}
+    let mut runner = match Runner::new(args.common) {
+        Ok(runner) => runner,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(format) = args.graph {
+        runner.each_input(Puzzle::DAY, |_, input| {
+            let graph = puzzle.parse(input)?.graph();
 
-impl FromStr for Schematic {
-    type Err = Infallible;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut schematic = Schematic::default();
-        let mut active_part = None;
-
-        for ((x, y), char) in s
-            .lines()
-            .enumerate()
-            .flat_map(|(y, line)| line.char_indices().map(move |(x, char)| ((x, y), char)))
-        {
-            active_part = match (Entry::from(char), active_part.take()) {
-                (Entry::Value(digit), None) => Some(((x..=x, y), digit as usize)),
-                (Entry::Value(digit), Some(((range, y_old), value))) if y_old == y => {
-                    Some(((*range.start()..=x, y), 10 * value + digit as usize))
-                }
-                (Entry::Value(digit), Some(((range, y_old), value))) => {
-                    schematic.parts.insert((range, y_old), value);
-                    Some(((x..=x, y), digit as usize))
-                }
-                (Entry::Blank, None) => None,
-                (Entry::Blank, Some((key, value))) => {
-                    schematic.parts.insert(key, value);
-                    None
-                }
-                (Entry::Symbol(symbol), None) => {
-                    schematic.symbols.insert((x, y), symbol);
-                    None
-                }
-                (Entry::Symbol(symbol), Some((key, value))) => {
-                    schematic.symbols.insert((x, y), symbol);
-                    schematic.parts.insert(key, value);
-                    None
-                }
+            match format {
+                GraphFormat::Dot => print!("{}", graph.to_dot()),
+                GraphFormat::Json => println!("{}", graph.to_json()),
             }
-        }
 
-        Ok(schematic)
-    }
-}
+            Ok(())
+        });
+    } else if args.components {
+        runner.each_input(Puzzle::DAY, |path, input| {
+            let graph = puzzle.parse(input)?.graph();
 
-fn solve_part_1(input: &str) -> Option<u128> {
-    let schematic: Schematic = input.parse().ok()?;
-
-    let sum = schematic
-        .parts
-        .iter()
-        .filter_map(|((x_range, y), &part)| {
-            (x_range.start().saturating_sub(1)..=x_range.end().saturating_add(1))
-                .flat_map(|x| (y.saturating_sub(1)..=y.saturating_add(1)).map(move |y| (x, y)))
-                .find_map(|key| schematic.symbols.get(&key))
-                .map(move |_| part as u128)
-        })
-        .sum();
-
-    Some(sum)
-}
+            println!("{}:", path);
+            print!("{}", graph.report());
 
-fn solve_part_2(input: &str) -> Option<u128> {
-    let schematic: Schematic = input.parse().ok()?;
-
-    let sum = schematic
-        .symbols
-        .iter()
-        .filter(|(_, &symbol)| symbol == '*')
-        .filter_map(|(&(x, y), _)| {
-            let ys = y.saturating_sub(1)..=y.saturating_add(1);
-
-            let adjacent = schematic
-                .parts
-                .iter()
-                .filter(|((_, y), _)| ys.contains(y))
-                .filter(|((xs, _), _)| {
-                    (xs.start().saturating_sub(1)..=xs.end().saturating_add(1)).contains(&x)
-                })
-                .map(|(_, &value)| value as u128)
-                .take(3)
-                .collect::<Vec<_>>();
-
-            if adjacent.len() == 2 {
-                Some(adjacent.into_iter().product::<u128>())
-            } else {
-                None
-            }
-        })
-        .sum();
+            Ok(())
+        });
+    } else if args.render {
+        runner.each_input(Puzzle::DAY, |_, input| {
+            print!("{}", puzzle.parse(input)?.render(&puzzle.gear));
 
-    Some(sum)
-}
+            Ok(())
+        });
+    } else if let Some(png) = &args.png {
+        let mut image = None;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example_part_1() {
-        const INPUT: &str = r#"467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598.."#;
-        const RESULT: Option<u128> = Some(4361);
-
-        assert_eq!(solve_part_1(INPUT), RESULT);
-    }
+        runner.each_input(Puzzle::DAY, |_, input| {
+            image = Some(puzzle.parse(input)?.image(&puzzle.gear, args.cell_size)?);
 
-    #[test]
-    fn example_part_2() {
-        const INPUT: &str = r#"467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598.."#;
-        const RESULT: Option<u128> = Some(467835);
-
-        assert_eq!(solve_part_2(INPUT), RESULT);
+            Ok(())
+        });
+
+        // Errors saving belong to the image rather than the input it was drawn from
+        if let Some(Err(error)) = image.map(|image| image.save_with_format(png, ImageFormat::Png)) {
+            eprintln!("{}: {}", png, error);
+            return ExitCode::FAILURE;
+        }
+    } else if args.stream {
+        runner.run_stream::<Puzzle>(|input| {
+            let totals = day_3::totals(input, &puzzle.gear)?;
+
+            Ok((totals.part_1, totals.part_2))
+        });
+    } else {
+        runner.run_with(&puzzle);
     }
+
+    runner.finish()
 }
@@ -0,0 +1,173 @@
+use common::{Error, Grid};
+
+use crate::{Entry, GearRule, Schematic};
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Most pixels in an image, which hold 768 MiB as RGB.
+const MAX_PIXELS: u64 = 256 << 20;
+
+/// What a cell of the schematic contributes to the puzzle.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    #[default]
+    Blank,
+    /// A symbol which is not a gear.
+    Symbol,
+    Gear,
+    /// A digit of a number touching a symbol.
+    Part,
+    /// A digit of a number touching a gear.
+    GearPart,
+    /// A digit of a number touching no symbol.
+    Loose,
+}
+
+impl Role {
+    fn ansi(self) -> &'static str {
+        match self {
+            Self::Blank => "\x1b[2m",
+            Self::Symbol => "\x1b[35m",
+            Self::Gear => "\x1b[1;33m",
+            Self::Part => "\x1b[32m",
+            Self::GearPart => "\x1b[1;36m",
+            Self::Loose => "\x1b[31m",
+        }
+    }
+
+    fn rgb(self) -> image::Rgb<u8> {
+        image::Rgb(match self {
+            Self::Blank => [32, 32, 32],
+            Self::Symbol => [200, 0, 200],
+            Self::Gear => [255, 215, 0],
+            Self::Part => [0, 170, 0],
+            Self::GearPart => [0, 200, 255],
+            Self::Loose => [220, 40, 40],
+        })
+    }
+}
+
+impl Schematic {
+    /// Role of each cell, with gears and their parts found by the rule.
+    pub fn roles(&self, rule: &GearRule) -> Grid<Role> {
+        let mut roles = self.entries.map(|entry| match entry {
+            Entry::Symbol(_) => Role::Symbol,
+            Entry::Value(_) => Role::Loose,
+            Entry::Blank => Role::Blank,
+        });
+
+        for number in &self.numbers {
+            if self.symbols_around(number).next().is_some() {
+                number
+                    .positions()
+                    .for_each(|position| roles[position] = Role::Part);
+            }
+        }
+
+        for (position, parts) in self.gears(rule) {
+            roles[position] = Role::Gear;
+
+            for position in parts.iter().flat_map(|number| number.positions()) {
+                roles[position] = Role::GearPart;
+            }
+        }
+
+        roles
+    }
+
+    /// The schematic with each cell coloured by its role using ANSI escapes.
+    pub fn render(&self, rule: &GearRule) -> String {
+        let roles = self.roles(rule);
+        let mut output = String::new();
+
+        for (entries, roles) in self.entries.rows().zip(roles.rows()) {
+            let mut current = None;
+
+            for (&entry, &role) in entries.iter().zip(roles) {
+                if current != Some(role) {
+                    output.push_str(role.ansi());
+                    current = Some(role);
+                }
+
+                match entry {
+                    Entry::Symbol(symbol) => output.push(symbol),
+                    Entry::Value(digit) => output.push((b'0' + digit) as char),
+                    Entry::Blank => output.push('.'),
+                }
+            }
+
+            output.push_str(ANSI_RESET);
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Image of the schematic with a square block of `cell` pixels coloured by
+    /// each cell's role, or an error if it would have more than [`MAX_PIXELS`].
+    pub fn image(&self, rule: &GearRule, cell: u32) -> Result<image::RgbImage, Error> {
+        let side = |cells: usize| u32::try_from(cells).ok()?.checked_mul(cell);
+        let (width, height) = side(self.entries.width())
+            .zip(side(self.entries.height()))
+            .filter(|&(width, height)| width as u64 * height as u64 <= MAX_PIXELS)
+            .ok_or_else(|| Error::from("Image is too large"))?;
+
+        let roles = self.roles(rule);
+
+        Ok(image::RgbImage::from_fn(width, height, |x, y| {
+            roles[((y / cell) as usize, (x / cell) as usize)].rgb()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn example_roles() {
+        let puzzle = Puzzle::default();
+        let schematic = puzzle.parse(Puzzle::EXAMPLE).expect("Must parse example");
+
+        let roles = schematic.roles(&puzzle.gear);
+
+        assert_eq!(roles[(0, 0)], Role::GearPart);
+        assert_eq!(roles[(0, 5)], Role::Loose);
+        assert_eq!(roles[(0, 8)], Role::Blank);
+        assert_eq!(roles[(1, 3)], Role::Gear);
+        assert_eq!(roles[(2, 6)], Role::Part);
+        assert_eq!(roles[(3, 6)], Role::Symbol);
+        assert_eq!(roles[(4, 3)], Role::Symbol);
+
+        let image = schematic
+            .image(&puzzle.gear, 3)
+            .expect("Image must fit in memory");
+
+        assert_eq!(image.dimensions(), (30, 30));
+        assert_eq!(image.get_pixel(4, 2), &Role::GearPart.rgb());
+        assert_eq!(image.get_pixel(9, 5), &Role::Gear.rgb());
+
+        // A 200,000 pixel square would need 120 GB
+        let error = schematic.image(&puzzle.gear, 20_000).unwrap_err();
+
+        assert_eq!(error.message(), "Image is too large");
+    }
+
+    #[test]
+    fn render_runs() {
+        let schematic = "1.*2\n3..."
+            .parse::<Schematic>()
+            .expect("Must parse schematic");
+
+        let rule = "count=1".parse().expect("Must parse gear rule");
+
+        assert_eq!(
+            schematic.render(&rule),
+            "\x1b[31m1\x1b[2m.\x1b[1;33m*\x1b[1;36m2\x1b[0m\n\
+             \x1b[31m3\x1b[2m...\x1b[0m\n"
+        );
+    }
+}
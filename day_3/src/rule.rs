@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use common::{settings, Error};
+
+/// Cells counted as next to a symbol.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Directly above, below, left or right.
+    Four,
+    /// Including diagonals.
+    #[default]
+    Eight,
+}
+
+/// How the part numbers next to a gear form its ratio.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Sum,
+    #[default]
+    Product,
+    Max,
+}
+
+impl Combine {
+    /// Combine the values, or `None` if the result overflows.
+    pub fn apply(&self, values: &[u128]) -> Option<u128> {
+        match self {
+            Self::Sum => values
+                .iter()
+                .try_fold(0u128, |sum, &value| sum.checked_add(value)),
+            Self::Product => values
+                .iter()
+                .try_fold(1u128, |product, &value| product.checked_mul(value)),
+            Self::Max => Some(values.iter().copied().max().unwrap_or_default()),
+        }
+    }
+}
+
+/// Which symbols are gears, and how the part numbers next to them form ratios.
+///
+/// Written as `key=value` [settings](common::settings), where `count` sets
+/// both `min` and `max`, and a comma among the symbols is escaped as `\,`:
+///
+/// ```text
+/// symbols=*#\,
+/// min=2, max=3
+/// neighbourhood=4
+/// combine=sum
+/// ```
+///
+/// Unspecified entries keep the puzzle's rule: a `*` next to exactly two part
+/// numbers, including diagonally, with their product as its ratio.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+    pub symbols: Vec<char>,
+    /// Fewest part numbers next to a gear.
+    pub min: usize,
+    /// Most part numbers next to a gear.
+    pub max: usize,
+    pub neighbourhood: Neighbourhood,
+    pub combine: Combine,
+}
+
+impl Default for GearRule {
+    fn default() -> Self {
+        Self {
+            symbols: vec!['*'],
+            min: 2,
+            max: 2,
+            neighbourhood: Neighbourhood::default(),
+            combine: Combine::default(),
+        }
+    }
+}
+
+impl GearRule {
+    pub fn is_gear(&self, symbol: char) -> bool {
+        self.symbols.contains(&symbol)
+    }
+
+    /// Whether a symbol with this many part numbers next to it is a gear.
+    pub fn admits(&self, parts: usize) -> bool {
+        (self.min..=self.max).contains(&parts)
+    }
+}
+
+impl FromStr for GearRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rule = Self::default();
+
+        for setting in settings::settings(s, "Expected 'key=value'") {
+            let (key, value) = setting?;
+
+            let count = || {
+                value
+                    .parse::<usize>()
+                    .map_err(|_| Error::new("Could not parse count", value))
+            };
+
+            match key {
+                "symbols" if value.is_empty() => {
+                    return Err(Error::end_of("Missing gear symbols", value))
+                }
+                "symbols" => rule.symbols = settings::unescape(value).chars().collect(),
+                "count" => {
+                    rule.min = count()?;
+                    rule.max = rule.min;
+                }
+                "min" => rule.min = count()?,
+                "max" => rule.max = count()?,
+                "neighbourhood" => {
+                    rule.neighbourhood = match value {
+                        "4" => Neighbourhood::Four,
+                        "8" => Neighbourhood::Eight,
+                        _ => return Err(Error::new("Expected neighbourhood 4 or 8", value)),
+                    }
+                }
+                "combine" => {
+                    rule.combine = match value {
+                        "sum" => Combine::Sum,
+                        "product" => Combine::Product,
+                        "max" => Combine::Max,
+                        _ => return Err(Error::new("Expected sum, product or max", value)),
+                    }
+                }
+                _ => return Err(Error::new("Unknown gear rule key", key)),
+            }
+        }
+
+        if rule.min > rule.max {
+            return Err(Error::new("Minimum count is above maximum", s.trim()));
+        }
+
+        Ok(rule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rule() {
+        const INPUT: &str =
+            "# Variant\nsymbols=*\\,#, min=1\nmax = 3\nneighbourhood=4\ncombine=sum";
+
+        let rule = INPUT.parse::<GearRule>().expect("Must parse gear rule");
+
+        assert_eq!(
+            rule,
+            GearRule {
+                symbols: vec!['*', ',', '#'],
+                min: 1,
+                max: 3,
+                neighbourhood: Neighbourhood::Four,
+                combine: Combine::Sum,
+            }
+        );
+
+        assert_eq!("".parse::<GearRule>(), Ok(GearRule::default()));
+
+        let error = "count=2,combine=mean".parse::<GearRule>().unwrap_err();
+
+        assert_eq!(error.message(), "Expected sum, product or max");
+        assert_eq!(error.snippet(), "mean");
+
+        let error = "min=3, max=2".parse::<GearRule>().unwrap_err();
+
+        assert_eq!(error.message(), "Minimum count is above maximum");
+    }
+}
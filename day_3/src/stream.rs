@@ -0,0 +1,274 @@
+use std::{io::BufRead, ops::RangeInclusive};
+
+use common::{Error, Position};
+
+use crate::{numbers_in, Entry, GearRule, Neighbourhood, Number};
+
+/// Something found while streaming a schematic, as soon as every row it
+/// touches has been read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// A number touching a symbol.
+    Part {
+        row: usize,
+        columns: RangeInclusive<usize>,
+        value: usize,
+    },
+    /// A gear under the rule, with its ratio.
+    Gear { position: Position, ratio: u128 },
+}
+
+/// Answers to both parts found by streaming a schematic.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub part_1: u128,
+    pub part_2: u128,
+}
+
+/// A row of the schematic and the numbers written in it.
+struct Row {
+    row: usize,
+    cells: Vec<Entry>,
+    numbers: Vec<Number>,
+}
+
+impl Row {
+    /// Whether a symbol is within one column of the columns.
+    fn symbol_within(&self, columns: &RangeInclusive<usize>) -> bool {
+        let start = columns.start().saturating_sub(1);
+        let end = (columns.end() + 2).min(self.cells.len());
+
+        self.cells
+            .get(start..end)
+            .is_some_and(|cells| cells.iter().any(|entry| matches!(entry, Entry::Symbol(_))))
+    }
+}
+
+/// Read a schematic one line at a time, calling `emit` for each part number
+/// and gear once the rows on either side of it are known.
+///
+/// Only three rows are kept, so memory depends on the width of the schematic
+/// rather than its height.
+pub fn stream(
+    mut input: impl BufRead,
+    rule: &GearRule,
+    mut emit: impl FnMut(Event),
+) -> Result<(), Error> {
+    let mut line = String::new();
+    let mut window: [Option<Row>; 3] = [None, None, None];
+    let mut row = 0;
+
+    loop {
+        line.clear();
+
+        // Lines are not kept, so errors are given the location of their row
+        let read = input.read_line(&mut line).map_err(|error| {
+            Error::new("Could not read schematic", &error.to_string()).at(row + 1, 1)
+        })?;
+
+        let next = if read == 0 {
+            None
+        } else {
+            let text = line.trim_end_matches('\n').trim_end_matches('\r');
+            let cells = text
+                .char_indices()
+                .enumerate()
+                .map(|(column, (index, char))| {
+                    Entry::try_from(char).map_err(|message| {
+                        Error::new(message, &text[index..]).at(row + 1, column + 1)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if let Some(previous) = window[2].as_ref() {
+                if previous.cells.len() != cells.len() {
+                    return Err(Error::new("Rows must all be the same width", text).at(row + 1, 1));
+                }
+            }
+
+            let numbers = numbers_in(row, &cells);
+            let next = Row {
+                row,
+                cells,
+                numbers,
+            };
+
+            row += 1;
+            Some(next)
+        };
+
+        let done = next.is_none();
+
+        window.rotate_left(1);
+        window[2] = next;
+
+        if let [above, Some(current), below] = &window {
+            complete(above.as_ref(), current, below.as_ref(), rule, &mut emit)?;
+        }
+
+        if done {
+            return Ok(());
+        }
+    }
+}
+
+/// Emit the parts and gears of a row whose neighbours have been read.
+fn complete(
+    above: Option<&Row>,
+    current: &Row,
+    below: Option<&Row>,
+    rule: &GearRule,
+    emit: &mut impl FnMut(Event),
+) -> Result<(), Error> {
+    let rows = || above.into_iter().chain([current]).chain(below);
+
+    for number in &current.numbers {
+        if rows().any(|row| row.symbol_within(&number.columns)) {
+            emit(Event::Part {
+                row: number.row,
+                columns: number.columns.clone(),
+                value: number.value,
+            });
+        }
+    }
+
+    for (column, &entry) in current.cells.iter().enumerate() {
+        if !matches!(entry, Entry::Symbol(symbol) if rule.is_gear(symbol)) {
+            continue;
+        }
+
+        // Columns either side of the gear a number may reach from the rows above and below
+        let reach = match rule.neighbourhood {
+            Neighbourhood::Four => 0,
+            Neighbourhood::Eight => 1,
+        };
+
+        let touching = |row: &Row, reach: usize| {
+            row.numbers
+                .iter()
+                .filter(move |number| {
+                    *number.columns.start() <= column + reach
+                        && number.columns.end() + reach >= column
+                })
+                .map(|number| number.value as u128)
+                .collect::<Vec<_>>()
+        };
+
+        let parts = above
+            .map(|row| touching(row, reach))
+            .into_iter()
+            .chain([touching(current, 1)])
+            .chain(below.map(|row| touching(row, reach)))
+            .flatten()
+            .take(rule.max.saturating_add(1))
+            .collect::<Vec<_>>();
+
+        if rule.admits(parts.len()) {
+            let ratio = rule
+                .combine
+                .apply(&parts)
+                .ok_or_else(|| Error::from("Gear ratios overflowed"))?;
+
+            emit(Event::Gear {
+                position: (current.row, column),
+                ratio,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Answer both parts in a single pass over a schematic.
+pub fn totals(input: impl BufRead, rule: &GearRule) -> Result<Totals, Error> {
+    let mut totals = Totals::default();
+    let mut overflowed = false;
+
+    stream(input, rule, |event| match event {
+        Event::Part { value, .. } => totals.part_1 += value as u128,
+        Event::Gear { ratio, .. } => match totals.part_2.checked_add(ratio) {
+            Some(sum) => totals.part_2 = sum,
+            None => overflowed = true,
+        },
+    })?;
+
+    if overflowed {
+        return Err(Error::from("Gear ratios overflowed"));
+    }
+
+    Ok(totals)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Part, Solution};
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn matches_batch() {
+        for rule in [
+            "",
+            "combine=sum, min=1",
+            "symbols=*#$, count=1, neighbourhood=4",
+        ] {
+            let puzzle = Puzzle {
+                gear: rule.parse().expect("Must parse gear rule"),
+            };
+
+            let totals = totals(Puzzle::EXAMPLE.as_bytes(), &puzzle.gear);
+
+            let expected = Totals {
+                part_1: common::solve_with(&puzzle, Puzzle::EXAMPLE, Part::One).unwrap(),
+                part_2: common::solve_with(&puzzle, Puzzle::EXAMPLE, Part::Two).unwrap(),
+            };
+
+            assert_eq!(totals, Ok(expected), "rule '{}'", rule);
+        }
+    }
+
+    #[test]
+    fn emits_once_neighbours_are_read() {
+        let mut events = Vec::new();
+
+        stream(
+            "12.\r\n.*3\n...".as_bytes(),
+            &GearRule::default(),
+            |event| events.push(event),
+        )
+        .expect("Must stream schematic");
+
+        assert_eq!(
+            events,
+            vec![
+                Event::Part {
+                    row: 0,
+                    columns: 0..=1,
+                    value: 12
+                },
+                Event::Part {
+                    row: 1,
+                    columns: 2..=2,
+                    value: 3
+                },
+                Event::Gear {
+                    position: (1, 1),
+                    ratio: 36
+                },
+            ]
+        );
+
+        let error = totals("12.\n.*\n".as_bytes(), &GearRule::default()).unwrap_err();
+
+        assert_eq!(error.message(), "Rows must all be the same width");
+        assert_eq!(error.snippet(), ".*");
+        assert_eq!(error.location(), Some((2, 1)));
+
+        let error = totals(&b"12.\n.*\xff\n"[..], &GearRule::default()).unwrap_err();
+
+        assert_eq!(error.message(), "Could not read schematic");
+        assert_eq!(error.snippet(), "stream did not contain valid UTF-8");
+        assert_eq!(error.location(), Some((2, 1)));
+    }
+}
@@ -0,0 +1,162 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use common::{Error, Part, Solution};
+
+pub struct ScratchCard {
+    id: usize,
+    winners: Vec<usize>,
+    scratched: Vec<usize>,
+}
+
+impl ScratchCard {
+    fn matches(&self) -> usize {
+        self.scratched
+            .iter()
+            .filter(|number| self.winners.contains(number))
+            .count()
+    }
+}
+
+impl FromStr for ScratchCard {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_ascii_whitespace();
+
+        let Some("Card") = tokens.next() else {
+            return Err(Error::new("Missing 'Card' token", s));
+        };
+
+        let id = tokens
+            .next()
+            .ok_or_else(|| Error::end_of("Missing ID", s))?;
+
+        Ok(ScratchCard {
+            id: id
+                .strip_suffix(':')
+                .ok_or_else(|| Error::end_of("Missing ':' token", id))?
+                .parse()
+                .map_err(|_| Error::new("Could not parse ID", id))?,
+            winners: tokens
+                .by_ref()
+                .take_while(|&token| token != "|")
+                .map(|token| {
+                    token
+                        .parse()
+                        .map_err(|_| Error::new("Could not parse winners", token))
+                })
+                .collect::<Result<_, _>>()?,
+            scratched: tokens
+                .map(|token| {
+                    token
+                        .parse()
+                        .map_err(|_| Error::new("Could not parse scratched", token))
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+    const EXAMPLE: &'static str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
+
+    type Input<'a> = Vec<ScratchCard>;
+    type Answer = u128;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input.lines().map(|line| line.parse()).collect()
+    }
+
+    fn part_1(&self, cards: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        Ok(cards
+            .iter()
+            .map(|card| card.matches())
+            .filter(|&matches| matches > 0)
+            .map(|matches| 1 << (matches - 1))
+            .sum())
+    }
+
+    fn part_2(&self, cards: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        let (total, pending) = cards
+            .iter()
+            .enumerate()
+            .filter(|(index, card)| index + 1 == card.id)
+            .map(|(_, card)| card.matches())
+            .fold((0, VecDeque::new()), |(total, mut pending), matches| {
+                let count = 1 + pending.pop_front().unwrap_or(0);
+
+                let new = pending.len()..matches;
+
+                pending.iter_mut().take(matches).for_each(|x| *x += count);
+
+                pending.extend(new.map(|_| count));
+
+                (total + count, pending)
+            });
+
+        pending
+            .is_empty()
+            .then_some(total)
+            .ok_or_else(|| "Won copies of cards past the end of the table".into())
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn solve_part_2(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1() {
+        const INPUT: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
+        const RESULT: Option<u128> = Some(13);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_part_2() {
+        const INPUT: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
+        const RESULT: Option<u128> = Some(30);
+
+        assert_eq!(solve_part_2(INPUT), RESULT);
+    }
+
+    #[test]
+    fn locate_parse_error() {
+        const INPUT: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 2O 16 61 | 61 30 68 82 17 32 24 19"#;
+
+        let error = common::solve::<Puzzle>(INPUT, Part::One).unwrap_err();
+
+        assert_eq!(error.message(), "Could not parse winners");
+        assert_eq!(error.location(), Some((2, 15)));
+    }
+}
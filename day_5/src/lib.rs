@@ -0,0 +1,445 @@
+use std::{ops::Range, str::FromStr};
+
+use common::{profile::Counter, Error, Part, Solution};
+
+static MAP_LOOKUPS: Counter = Counter::new("map lookups");
+static RULE_APPLICATIONS: Counter = Counter::new("rule applications");
+
+#[derive(Debug, Clone)]
+struct Inventory {
+    item_type: String,
+    values: Vec<usize>,
+}
+
+impl FromStr for Inventory {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.trim().split_ascii_whitespace();
+
+        let item_type = tokens
+            .next()
+            .ok_or_else(|| Error::end_of("Missing inventory", s))?;
+
+        let item_type = item_type
+            .strip_suffix(':')
+            .ok_or_else(|| Error::end_of("Expected ':'", item_type))?
+            .to_string();
+
+        let item_type = if let Some(stripped) = item_type.strip_suffix('s') {
+            stripped.to_string()
+        } else {
+            item_type
+        };
+
+        let values = tokens
+            .map(|token| {
+                token
+                    .parse()
+                    .map_err(|_| Error::new("Could not parse inventory values", token))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Inventory { item_type, values })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    source: Range<usize>,
+    destination: Range<usize>,
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_ascii_whitespace();
+
+        let mut field = |missing| {
+            let token = tokens.next().ok_or_else(|| Error::end_of(missing, s))?;
+
+            token
+                .parse::<usize>()
+                .map_err(|_| Error::new("Could not parse", token))
+        };
+
+        let destination_start = field("Missing 'destination range start' field in mapping")?;
+
+        let source_start = field("Missing 'source range start' field in mapping")?;
+
+        let range = field("Missing 'range length' field in mapping")?;
+
+        if let Some(token) = tokens.next() {
+            return Err(Error::new("Unexpected token", token));
+        };
+
+        let source = source_start..(source_start + range);
+        let destination = destination_start..(destination_start + range);
+
+        Ok(Rule {
+            source,
+            destination,
+        })
+    }
+}
+
+/// Portions of a range below, inside and above a [`Rule`], with the inside portion mapped.
+type RangeSplit = (
+    Option<Range<usize>>,
+    Option<Range<usize>>,
+    Option<Range<usize>>,
+);
+
+impl Rule {
+    fn apply_range(&self, source: Range<usize>) -> RangeSplit {
+        RULE_APPLICATIONS.increment();
+
+        let left = source.start.min(self.source.start)..source.end.min(self.source.start);
+        let centre = source.start.max(self.source.start)..source.end.min(self.source.end);
+        let right = source.start.max(self.source.end)..source.end.max(self.source.end);
+
+        let left = (!left.is_empty()).then_some(left);
+        let centre = (!centre.is_empty()).then(|| {
+            let start = self.destination.start + centre.start - self.source.start;
+            let end = self.destination.start + centre.end - self.source.start;
+            start..end
+        });
+        let right = (!right.is_empty()).then_some(right);
+
+        (left, centre, right)
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    from: String,
+    to: String,
+    rules: Vec<Rule>,
+}
+
+impl Map {
+    fn map(&self, s: Range<usize>) -> Vec<Range<usize>> {
+        let (mut a, b) = self.rules.iter().fold((vec![], vec![s]), |(a, b), rule| {
+            b.into_iter().fold((a, vec![]), |(mut a, mut b), range| {
+                let (too_small, mapped, too_large) = rule.apply_range(range);
+
+                if let Some(range) = too_small {
+                    b.push(range);
+                }
+
+                if let Some(range) = too_large {
+                    b.push(range);
+                }
+
+                if let Some(range) = mapped {
+                    a.push(range);
+                }
+
+                (a, b)
+            })
+        });
+
+        a.extend(b);
+
+        a
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.trim().lines();
+
+        let header = lines
+            .next()
+            .ok_or_else(|| Error::end_of("Missing header", s))?;
+
+        let mut split = header
+            .strip_suffix("map:")
+            .ok_or_else(|| Error::end_of("Missing 'map' token", header))?
+            .split("-to-");
+
+        let from = split
+            .next()
+            .ok_or_else(|| Error::new("Missing 'from' field in header", header))?
+            .trim()
+            .to_string();
+
+        let to = split
+            .next()
+            .ok_or_else(|| Error::end_of("Missing 'to' field in header", header))?
+            .trim()
+            .to_string();
+
+        if let Some(token) = split.next() {
+            return Err(Error::new("Unexpected token", token));
+        };
+
+        let rules = lines.map(|line| line.parse()).collect::<Result<_, _>>()?;
+
+        Ok(Map { from, to, rules })
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    inventory: Inventory,
+    maps: Vec<Map>,
+}
+
+impl FromStr for Almanac {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.trim().lines();
+
+        Ok(Almanac {
+            inventory: lines
+                .next()
+                .ok_or_else(|| Error::end_of("Missing inventory line", s))?
+                .parse()?,
+            maps: lines.try_fold(Vec::<Map>::new(), |mut maps, line| {
+                if line.trim().is_empty() {
+                    // Blank lines separate maps
+                } else if line.trim_end().ends_with("map:") {
+                    maps.push(line.parse()?);
+                } else {
+                    maps.last_mut()
+                        .ok_or_else(|| Error::new("Orphaned rule", line))?
+                        .rules
+                        .push(line.parse()?);
+                }
+                Ok::<_, Error>(maps)
+            })?,
+        })
+    }
+}
+
+impl Almanac {
+    fn map_for(&self, item_type: &str) -> Option<&Map> {
+        MAP_LOOKUPS.increment();
+
+        self.maps.iter().find(|map| map.from == item_type)
+    }
+}
+
+#[derive(Default)]
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+    const EXAMPLE: &'static str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"#;
+
+    type Input<'a> = Almanac;
+    type Answer = usize;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input.parse()
+    }
+
+    fn part_1(&self, almanac: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        let mut inventory = almanac.inventory.clone();
+
+        while inventory.item_type != "location" {
+            let map = almanac
+                .map_for(inventory.item_type.as_str())
+                .ok_or("Missing map for inventory")?;
+            inventory.item_type = map.to.clone();
+            inventory.values = inventory
+                .values
+                .into_iter()
+                .map(|value| map.map(value..(value + 1))[0].start)
+                .collect();
+        }
+
+        inventory
+            .values
+            .into_iter()
+            .min()
+            .ok_or_else(|| "Empty inventory".into())
+    }
+
+    fn part_2(&self, almanac: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        let mut inventory = almanac.inventory.clone();
+
+        while inventory.item_type != "location" {
+            let map = almanac
+                .map_for(inventory.item_type.as_str())
+                .ok_or("Missing map for inventory")?;
+            inventory.item_type = map.to.clone();
+            inventory.values = inventory
+                .values
+                .chunks(2)
+                .map(|chunk| chunk[0]..(chunk[0] + chunk[1]))
+                .flat_map(|range| map.map(range))
+                .flat_map(|range| [range.start, range.len()])
+                .collect();
+        }
+
+        inventory
+            .values
+            .chunks(2)
+            .map(|chunk| chunk[0])
+            .min()
+            .ok_or_else(|| "Empty inventory".into())
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Option<usize> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn solve_part_2(input: &str) -> Option<usize> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_example_rule() {
+        let rule = "50 98 2"
+            .parse::<Rule>()
+            .expect("Must be able to parse rule");
+
+        let expected = (Some(0..98), Some(50..52), Some(100..usize::MAX));
+
+        let mapped = rule.apply_range(0..usize::MAX);
+
+        assert_eq!(mapped, expected);
+    }
+
+    #[test]
+    fn example_map_range() {
+        let map = Map::from_str(
+            r#"seed-to-soil map:
+50 98 2
+52 50 48"#,
+        )
+        .expect("Must be able to parse example map");
+
+        let expected = vec![0..50, 50..52, 52..100, 100..usize::MAX];
+
+        let mut mapped = map.map(0..usize::MAX);
+
+        mapped.sort_by_key(|a| a.end);
+
+        assert_eq!(mapped, expected);
+    }
+
+    #[test]
+    fn example_part_1() {
+        const INPUT: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"#;
+        const RESULT: Option<usize> = Some(35);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_part_2() {
+        const INPUT: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4"#;
+        const RESULT: Option<usize> = Some(46);
+
+        assert_eq!(solve_part_2(INPUT), RESULT);
+    }
+}
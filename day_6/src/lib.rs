@@ -0,0 +1,192 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use common::{Error, Part, Solution};
+
+struct Race {
+    time: u128,
+    distance: u128,
+}
+
+impl Race {
+    fn test(&self, hold: u128) -> u128 {
+        const INITIAL_SPEED: u128 = 0; // 0 mm/ms
+        const ACCELERATION: u128 = 1; // 1 mm/(ms^2)
+
+        let start_time = hold.min(self.time);
+        let start_speed = INITIAL_SPEED + ACCELERATION * start_time;
+
+        (self.time - start_time) * start_speed
+    }
+
+    fn record_breakers(&self) -> RangeInclusive<u128> {
+        // Need to find the minimum and maximum hold time
+        let (min, max) = (0..=self.time)
+            .map(|hold| (hold, self.test(hold)))
+            .filter(|&(_, distance)| distance > self.distance)
+            .fold((u128::MAX, 0), |(min, max), (hold, _)| {
+                (min.min(hold), max.max(hold))
+            });
+        min..=max
+    }
+}
+
+pub struct Competition {
+    races: Vec<Race>,
+}
+
+impl FromStr for Competition {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let line = lines
+            .next()
+            .ok_or_else(|| Error::end_of("Unexpected EOF", s))?;
+
+        let times = line
+            .strip_prefix("Time:")
+            .ok_or_else(|| Error::new("Missing 'Time:' prefix", line))?
+            .trim()
+            .split_ascii_whitespace()
+            .map(|token| {
+                token
+                    .parse()
+                    .map_err(|_| Error::new("Could not parse times", token))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let line = lines
+            .next()
+            .ok_or_else(|| Error::end_of("Unexpected EOF", s))?;
+
+        let distances = line
+            .strip_prefix("Distance:")
+            .ok_or_else(|| Error::new("Missing 'Distance:' prefix", line))?
+            .trim()
+            .split_ascii_whitespace()
+            .map(|token| {
+                token
+                    .parse()
+                    .map_err(|_| Error::new("Could not parse distances", token))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Competition {
+            races: times
+                .into_iter()
+                .zip(distances)
+                .map(|(time, distance)| Race { time, distance })
+                .collect(),
+        })
+    }
+}
+
+pub struct TheBigCompetition {
+    race: Race,
+}
+
+impl FromStr for TheBigCompetition {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let line = lines
+            .next()
+            .ok_or_else(|| Error::end_of("Unexpected EOF", s))?;
+
+        let values = line
+            .strip_prefix("Time:")
+            .ok_or_else(|| Error::new("Missing 'Time:' prefix", line))?;
+
+        let time = values
+            .split_ascii_whitespace()
+            .collect::<String>()
+            .parse()
+            .map_err(|_| Error::new("Could not parse time", values.trim()))?;
+
+        let line = lines
+            .next()
+            .ok_or_else(|| Error::end_of("Unexpected EOF", s))?;
+
+        let values = line
+            .strip_prefix("Distance:")
+            .ok_or_else(|| Error::new("Missing 'Distance:' prefix", line))?;
+
+        let distance = values
+            .split_ascii_whitespace()
+            .collect::<String>()
+            .parse()
+            .map_err(|_| Error::new("Could not parse distance", values.trim()))?;
+
+        Ok(TheBigCompetition {
+            race: Race { time, distance },
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+    const EXAMPLE: &'static str = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+
+    type Input<'a> = (Competition, TheBigCompetition);
+    type Answer = u128;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        Ok((input.parse()?, input.parse()?))
+    }
+
+    fn part_1(&self, (comp, _): &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        let result = comp
+            .races
+            .iter()
+            .map(|race| race.record_breakers())
+            .map(|range| range.count() as u128)
+            .product();
+
+        Ok(result)
+    }
+
+    fn part_2(&self, (_, comp): &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        let result = comp.race.record_breakers().count() as u128;
+
+        Ok(result)
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn solve_part_2(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1() {
+        const INPUT: &str = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+        const RESULT: Option<u128> = Some(288);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_part_2() {
+        const INPUT: &str = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+        const RESULT: Option<u128> = Some(71503);
+
+        assert_eq!(solve_part_2(INPUT), RESULT);
+    }
+}
@@ -0,0 +1,274 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use common::{Error, Part, Solution};
+
+mod main_part_2;
+
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl TryFrom<char> for Card {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Self::Two),
+            '3' => Ok(Self::Three),
+            '4' => Ok(Self::Four),
+            '5' => Ok(Self::Five),
+            '6' => Ok(Self::Six),
+            '7' => Ok(Self::Seven),
+            '8' => Ok(Self::Eight),
+            '9' => Ok(Self::Nine),
+            'T' => Ok(Self::Ten),
+            'J' => Ok(Self::Jack),
+            'Q' => Ok(Self::Queen),
+            'K' => Ok(Self::King),
+            'A' => Ok(Self::Ace),
+            _ => Err("Unknown card"),
+        }
+    }
+}
+
+impl Card {
+    fn all() -> impl Iterator<Item = Self> {
+        [
+            Self::Two,
+            Self::Three,
+            Self::Four,
+            Self::Five,
+            Self::Six,
+            Self::Seven,
+            Self::Eight,
+            Self::Nine,
+            Self::Ten,
+            Self::Jack,
+            Self::Queen,
+            Self::King,
+            Self::Ace,
+        ]
+        .into_iter()
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+struct Hand {
+    cards: [Card; 5],
+}
+
+impl FromStr for Hand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let mut chars = s.char_indices().map(|(index, char)| {
+            Card::try_from(char)
+                .map_err(|message| Error::new(message, &s[index..index + char.len_utf8()]))
+        });
+
+        let mut next = || {
+            chars
+                .next()
+                .unwrap_or_else(|| Err(Error::end_of("Not Enough Cards", s)))
+        };
+
+        let hand = Self {
+            cards: [next()?, next()?, next()?, next()?, next()?],
+        };
+
+        if let Some((index, _)) = s.char_indices().nth(5) {
+            return Err(Error::new("Too Many Cards", &s[index..]));
+        };
+
+        Ok(hand)
+    }
+}
+
+impl Hand {
+    fn classify(&self) -> HandType {
+        let mut counts = Card::all()
+            .map(|target| self.cards.iter().filter(|&&card| card == target).count())
+            .collect::<Vec<_>>();
+
+        counts.sort();
+
+        match counts[..] {
+            [.., 5] => HandType::FiveOfAKind,
+            [.., 4] => HandType::FourOfAKind,
+            [.., 2, 3] => HandType::FullHouse,
+            [.., 3] => HandType::ThreeOfAKind,
+            [.., 2, 2] => HandType::TwoPair,
+            [.., 2] => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let by_classification = self.classify().cmp(&other.classify());
+
+        let Ordering::Equal = by_classification else {
+            return by_classification;
+        };
+
+        let by_higher_card = self
+            .cards
+            .iter()
+            .zip(other.cards.iter())
+            .map(|(a, b)| a.cmp(b))
+            .find(|&ordering| ordering != Ordering::Equal);
+
+        if let Some(ordering) = by_higher_card {
+            ordering
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+pub struct Game {
+    hands: Vec<(Hand, u32)>,
+}
+
+impl FromStr for Game {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hands = s
+            .trim()
+            .lines()
+            .map(|line| {
+                let mut tokens = line.split_ascii_whitespace();
+
+                let hand = tokens
+                    .next()
+                    .ok_or_else(|| Error::end_of("Missing Hand", line))?
+                    .parse()?;
+
+                let bid = tokens
+                    .next()
+                    .ok_or_else(|| Error::end_of("Missing Bid", line))?;
+
+                let bid = bid
+                    .parse()
+                    .map_err(|_| Error::new("Could not parse bid", bid))?;
+
+                Ok((hand, bid))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Game { hands })
+    }
+}
+
+impl Game {
+    fn score(&self) -> u128 {
+        let mut hands = self.hands.clone();
+
+        hands.sort_by_key(|(hand, _)| *hand);
+
+        hands
+            .into_iter()
+            .enumerate()
+            .map(|(index, (_, bid))| (index as u128 + 1) * (bid as u128))
+            .sum()
+    }
+}
+
+#[derive(Default)]
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+    const EXAMPLE: &'static str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+
+    type Input<'a> = (Game, main_part_2::Game);
+    type Answer = u128;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        Ok((input.parse()?, input.parse()?))
+    }
+
+    fn part_1(&self, (game, _): &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        Ok(game.score())
+    }
+
+    fn part_2(&self, (_, game): &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        Ok(game.score())
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn solve_part_2(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1() {
+        const INPUT: &str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+        const RESULT: Option<u128> = Some(6440);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_part_2() {
+        const INPUT: &str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483"#;
+        const RESULT: Option<u128> = Some(5905);
+
+        assert_eq!(solve_part_2(INPUT), RESULT);
+    }
+}
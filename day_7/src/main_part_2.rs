@@ -1,5 +1,7 @@
 use std::{cmp::Ordering, collections::HashMap, str::FromStr};
 
+use common::Error;
+
 #[derive(PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
 enum Card {
     Joker,
@@ -67,23 +69,28 @@ struct Hand {
 }
 
 impl FromStr for Hand {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut chars = s.trim().chars().map(Card::try_from);
+        let s = s.trim();
+
+        let mut chars = s.char_indices().map(|(index, char)| {
+            Card::try_from(char)
+                .map_err(|message| Error::new(message, &s[index..index + char.len_utf8()]))
+        });
+
+        let mut next = || {
+            chars
+                .next()
+                .unwrap_or_else(|| Err(Error::end_of("Not Enough Cards", s)))
+        };
 
         let hand = Self {
-            cards: [
-                chars.next().ok_or("Not Enough Cards")??,
-                chars.next().ok_or("Not Enough Cards")??,
-                chars.next().ok_or("Not Enough Cards")??,
-                chars.next().ok_or("Not Enough Cards")??,
-                chars.next().ok_or("Not Enough Cards")??,
-            ],
+            cards: [next()?, next()?, next()?, next()?, next()?],
         };
 
-        let None = chars.next() else {
-            return Err("Too Many Cards");
+        if let Some((index, _)) = s.char_indices().nth(5) {
+            return Err(Error::new("Too Many Cards", &s[index..]));
         };
 
         Ok(hand)
@@ -174,12 +181,12 @@ enum HandType {
     FiveOfAKind,
 }
 
-struct Game {
+pub struct Game {
     hands: Vec<(Hand, u32)>,
 }
 
 impl FromStr for Game {
-    type Err = &'static str;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let hands = s
@@ -188,24 +195,29 @@ impl FromStr for Game {
             .map(|line| {
                 let mut tokens = line.split_ascii_whitespace();
 
-                let hand = tokens.next().ok_or("Missing Hand")?.parse()?;
+                let hand = tokens
+                    .next()
+                    .ok_or_else(|| Error::end_of("Missing Hand", line))?
+                    .parse()?;
 
                 let bid = tokens
                     .next()
-                    .ok_or("Missing Bid")?
+                    .ok_or_else(|| Error::end_of("Missing Bid", line))?;
+
+                let bid = bid
                     .parse()
-                    .map_err(|_| "Could not parse bid")?;
+                    .map_err(|_| Error::new("Could not parse bid", bid))?;
 
                 Ok((hand, bid))
             })
-            .collect::<Result<_, _>>()?;
+            .collect::<Result<_, Error>>()?;
 
         Ok(Game { hands })
     }
 }
 
 impl Game {
-    fn score(&self) -> u128 {
+    pub fn score(&self) -> u128 {
         let mut hands = self.hands.clone();
 
         hands.sort_by_key(|(hand, _)| *hand);
@@ -217,9 +229,3 @@ impl Game {
             .sum()
     }
 }
-
-pub fn solve_part_2(input: &str) -> Option<u128> {
-    let game: Game = input.parse().ok()?;
-
-    Some(game.score())
-}
@@ -0,0 +1,297 @@
+use std::{collections::HashMap, str::FromStr};
+
+use common::{profile::Counter, Error, Part, Solution};
+
+static NODE_LOOKUPS: Counter = Counter::new("node lookups");
+
+enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err("Unknown Direction"),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+struct NodeId([char; 3]);
+
+impl NodeId {
+    fn is_start(&self) -> bool {
+        self.0[2] == 'A'
+    }
+
+    fn is_end(&self) -> bool {
+        self.0[2] == 'Z'
+    }
+}
+
+impl FromStr for NodeId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let mut chars = s.chars();
+
+        let mut next = || {
+            chars
+                .next()
+                .ok_or_else(|| Error::end_of("Missing ID Symbol", s))
+        };
+
+        let result = Self([next()?, next()?, next()?]);
+
+        if let Some((index, _)) = s.char_indices().nth(3) {
+            return Err(Error::new("Unexpected Symbols", &s[index..]));
+        };
+
+        Ok(result)
+    }
+}
+
+struct Node {
+    id: NodeId,
+    left: NodeId,
+    right: NodeId,
+}
+
+impl FromStr for Node {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split('=');
+
+        let id = tokens
+            .next()
+            .ok_or_else(|| Error::end_of("Missing Node ID", s))?
+            .parse()?;
+
+        let children = tokens
+            .next()
+            .ok_or_else(|| Error::end_of("Missing Child Node IDs", s))?
+            .trim();
+
+        let mut children = children
+            .strip_prefix('(')
+            .ok_or_else(|| Error::new("Missing Opening Brace", children))?
+            .strip_suffix(')')
+            .ok_or_else(|| Error::end_of("Missing Closing Brace", children))?
+            .split(',');
+
+        let left = children
+            .next()
+            .ok_or_else(|| Error::end_of("Missing Left Child", s))?
+            .parse()?;
+
+        let right = children
+            .next()
+            .ok_or_else(|| Error::end_of("Missing Right Child", s))?
+            .parse()?;
+
+        if let Some(child) = children.next() {
+            return Err(Error::new("Unexpected Children", child));
+        };
+
+        if let Some(token) = tokens.next() {
+            return Err(Error::new("Unexpected Tokens", token));
+        };
+
+        Ok(Self { id, left, right })
+    }
+}
+
+pub struct Map {
+    instructions: Vec<Direction>,
+    graph: HashMap<NodeId, (NodeId, NodeId)>,
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let line = lines
+            .next()
+            .ok_or_else(|| Error::end_of("Missing Instructions", s))?;
+
+        let instructions = line
+            .char_indices()
+            .map(|(index, direction)| {
+                direction.try_into().map_err(|message| {
+                    Error::new(message, &line[index..index + direction.len_utf8()])
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        lines.next();
+
+        let graph = lines
+            .map(|line| {
+                line.parse::<Node>()
+                    .map(|node| (node.id, (node.left, node.right)))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            instructions,
+            graph,
+        })
+    }
+}
+
+impl Map {
+    fn steps_to_end(&self, start: NodeId) -> Option<u128> {
+        self.instructions
+            .iter()
+            .cycle()
+            .scan(start, |position, direction| {
+                if position.is_end() {
+                    return None;
+                }
+
+                NODE_LOOKUPS.increment();
+
+                let Some(&(left, right)) = self.graph.get(position) else {
+                    return Some(Err("At impossible position!"));
+                };
+
+                *position = match direction {
+                    Direction::Left => left,
+                    Direction::Right => right,
+                };
+
+                Some(Ok(1))
+            })
+            .try_fold(0, |sum, step| step.map(|step| step + sum))
+            .ok()
+    }
+}
+
+/// Get the Greatest Common Devisor (GCD) of the provided numbers.
+/// From [Victor I. Afolabi](https://gist.github.com/victor-iyi/8a84185c1d52419b0d4915a648d5e3e1)
+fn gcd(mut n: u128, mut m: u128) -> u128 {
+    assert!(n != 0 && m != 0);
+
+    while m != 0 {
+        if m < n {
+            std::mem::swap(&mut m, &mut n);
+        }
+        m %= n;
+    }
+
+    n
+}
+
+#[derive(Default)]
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+    const EXAMPLE: &'static str = r#"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"#;
+
+    type Input<'a> = Map;
+    type Answer = u128;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input.parse()
+    }
+
+    fn part_1(&self, map: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        map.steps_to_end(NodeId(['A', 'A', 'A']))
+            .ok_or_else(|| "Could not reach the end".into())
+    }
+
+    fn part_2(&self, map: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        map.graph
+            .keys()
+            .filter(|node| node.is_start())
+            .map(|&start| map.steps_to_end(start))
+            .try_fold(0, |cycle, steps| {
+                let steps = steps?;
+
+                if cycle == 0 {
+                    Some(steps)
+                } else {
+                    Some(steps / gcd(steps, cycle) * cycle)
+                }
+            })
+            .ok_or_else(|| "Could not reach the end".into())
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn solve_part_2(input: &str) -> Option<u128> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1_part_1() {
+        const INPUT: &str = r#"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"#;
+        const RESULT: Option<u128> = Some(2);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_2_part_1() {
+        const INPUT: &str = r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"#;
+        const RESULT: Option<u128> = Some(6);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_1_part_2() {
+        const INPUT: &str = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"#;
+        const RESULT: Option<u128> = Some(6);
+
+        assert_eq!(solve_part_2(INPUT), RESULT);
+    }
+}
@@ -0,0 +1,132 @@
+use common::{Error, Part, Solution};
+
+struct Predictor<T> {
+    wave: Vec<T>,
+}
+
+impl Iterator for Predictor<i64> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.wave.iter_mut().rev().fold(None, |sum, diff| {
+            *diff += sum.unwrap_or(0);
+            Some(*diff)
+        })
+    }
+}
+
+impl FromIterator<i64> for Predictor<i64> {
+    fn from_iter<T: IntoIterator<Item = i64>>(iter: T) -> Self {
+        let mut wave = iter
+            .into_iter()
+            .fold(Vec::<i64>::new(), |mut wave, mut item| {
+                for old in wave.iter_mut() {
+                    std::mem::swap(old, &mut item);
+                    item = *old - item;
+                }
+
+                wave.push(item);
+
+                wave
+            });
+
+        while let Some(0) = wave.last() {
+            wave.pop();
+        }
+
+        wave.push(0);
+
+        Self { wave }
+    }
+}
+
+#[derive(Default)]
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+    const EXAMPLE: &'static str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+
+    type Input<'a> = Vec<Vec<i64>>;
+    type Answer = i64;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_ascii_whitespace()
+                    .map(|token| {
+                        token
+                            .parse::<i64>()
+                            .map_err(|_| Error::new("Could not parse history", token))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_1(&self, histories: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        histories
+            .iter()
+            .map(|history| {
+                history
+                    .iter()
+                    .copied()
+                    .collect::<Predictor<_>>()
+                    .next()
+                    .ok_or_else(|| "Empty history".into())
+            })
+            .try_fold(0, |sum, item: Result<i64, Error>| Ok(sum + item?))
+    }
+
+    fn part_2(&self, histories: &Self::Input<'_>) -> Result<Self::Answer, Error> {
+        histories
+            .iter()
+            .map(|history| {
+                history
+                    .iter()
+                    .copied()
+                    .rev()
+                    .collect::<Predictor<_>>()
+                    .next()
+                    .ok_or_else(|| "Empty history".into())
+            })
+            .try_fold(0, |sum, item: Result<i64, Error>| Ok(sum + item?))
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Option<i64> {
+    common::solve::<Puzzle>(input, Part::One).ok()
+}
+
+pub fn solve_part_2(input: &str) -> Option<i64> {
+    common::solve::<Puzzle>(input, Part::Two).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_part_1() {
+        const INPUT: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+        const RESULT: Option<i64> = Some(114);
+
+        assert_eq!(solve_part_1(INPUT), RESULT);
+    }
+
+    #[test]
+    fn example_part_2() {
+        const INPUT: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45"#;
+        const RESULT: Option<i64> = Some(2);
+
+        assert_eq!(solve_part_2(INPUT), RESULT);
+    }
+}